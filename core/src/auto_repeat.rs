@@ -0,0 +1,79 @@
+/// Horizontal direction the player can move the falling tetromino to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Repeats horizontal moves while a direction key is held: after the Delayed Auto Shift (DAS),
+/// the tetromino moves again every Auto Repeat Rate (ARR) frames. Pressing the key moves it once,
+/// this is up to the caller
+pub struct AutoRepeat {
+    das: u32,
+    arr: u32,
+    left: bool,
+    right: bool,
+    // last pressed direction that is still held
+    direction: Option<Direction>,
+    // frames before the next repeated move
+    countdown: u32,
+}
+
+impl AutoRepeat {
+    /// `das` and `arr` are in frames, an ARR of 0 moves the tetromino as far as possible at once
+    pub fn new(das: u32, arr: u32) -> AutoRepeat {
+        AutoRepeat {
+            das: das,
+            arr: arr,
+            left: false,
+            right: false,
+            direction: None,
+            countdown: 0,
+        }
+    }
+
+    pub fn press(&mut self, direction: Direction) {
+        match direction {
+            Direction::Left => self.left = true,
+            Direction::Right => self.right = true,
+        }
+        self.direction = Some(direction);
+        self.countdown = self.das;
+    }
+
+    /// Stops repeating a direction. If the other direction is still held, it repeats again after
+    /// the DAS
+    pub fn release(&mut self, direction: Direction) {
+        match direction {
+            Direction::Left => self.left = false,
+            Direction::Right => self.right = false,
+        }
+        if self.direction == Some(direction) {
+            self.direction = match direction {
+                Direction::Left if self.right => Some(Direction::Right),
+                Direction::Right if self.left => Some(Direction::Left),
+                _ => None,
+            };
+            self.countdown = self.das;
+        }
+    }
+
+    /// Advances one frame and returns the direction and the number of moves to make during this
+    /// frame (`u32::MAX` with an ARR of 0: the tetromino moves until it is blocked)
+    pub fn step(&mut self) -> Option<(Direction, u32)> {
+        let direction = self.direction?;
+        if self.countdown > 1 {
+            self.countdown -= 1;
+            return None;
+        }
+        if self.arr == 0 {
+            self.countdown = 0;
+            return Some((direction, u32::MAX));
+        }
+        self.countdown = self.arr;
+        Some((direction, 1))
+    }
+}
+
+#[cfg(test)]
+mod auto_repeat_tests;
@@ -0,0 +1,85 @@
+use super::*;
+
+/// Frames at which `step` returned moves, and how many, during `frames` frames
+fn moves(auto_repeat: &mut AutoRepeat, frames: u32) -> Vec<(u32, Direction, u32)> {
+    (1..=frames)
+        .filter_map(|frame| {
+            auto_repeat
+                .step()
+                .map(|(direction, moves)| (frame, direction, moves))
+        })
+        .collect()
+}
+
+#[test]
+fn nothing_repeats_without_a_held_key() {
+    let mut auto_repeat = AutoRepeat::new(10, 2);
+    assert_eq!(moves(&mut auto_repeat, 20), vec![]);
+}
+
+#[test]
+fn held_key_repeats_every_arr_after_the_das() {
+    let mut auto_repeat = AutoRepeat::new(10, 2);
+    auto_repeat.press(Direction::Left);
+    assert_eq!(
+        moves(&mut auto_repeat, 15),
+        vec![
+            (10, Direction::Left, 1),
+            (12, Direction::Left, 1),
+            (14, Direction::Left, 1),
+        ]
+    );
+}
+
+#[test]
+fn zero_arr_moves_as_far_as_possible() {
+    let mut auto_repeat = AutoRepeat::new(3, 0);
+    auto_repeat.press(Direction::Right);
+    assert_eq!(
+        moves(&mut auto_repeat, 4),
+        vec![
+            (3, Direction::Right, u32::MAX),
+            (4, Direction::Right, u32::MAX)
+        ]
+    );
+}
+
+#[test]
+fn released_key_stops_repeating() {
+    let mut auto_repeat = AutoRepeat::new(2, 1);
+    auto_repeat.press(Direction::Left);
+    moves(&mut auto_repeat, 5);
+    auto_repeat.release(Direction::Left);
+    assert_eq!(moves(&mut auto_repeat, 5), vec![]);
+}
+
+#[test]
+fn last_pressed_direction_wins() {
+    let mut auto_repeat = AutoRepeat::new(2, 1);
+    auto_repeat.press(Direction::Left);
+    auto_repeat.press(Direction::Right);
+    assert_eq!(moves(&mut auto_repeat, 2), vec![(2, Direction::Right, 1)]);
+}
+
+#[test]
+fn releasing_the_last_pressed_direction_goes_back_to_the_other_after_the_das() {
+    let mut auto_repeat = AutoRepeat::new(3, 1);
+    auto_repeat.press(Direction::Left);
+    auto_repeat.press(Direction::Right);
+    moves(&mut auto_repeat, 5);
+    auto_repeat.release(Direction::Right);
+    assert_eq!(
+        moves(&mut auto_repeat, 4),
+        vec![(3, Direction::Left, 1), (4, Direction::Left, 1)]
+    );
+}
+
+#[test]
+fn releasing_the_other_direction_does_not_restart_the_das() {
+    let mut auto_repeat = AutoRepeat::new(3, 1);
+    auto_repeat.press(Direction::Left);
+    auto_repeat.press(Direction::Right);
+    moves(&mut auto_repeat, 2);
+    auto_repeat.release(Direction::Left);
+    assert_eq!(moves(&mut auto_repeat, 1), vec![(1, Direction::Right, 1)]);
+}
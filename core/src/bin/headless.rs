@@ -0,0 +1,81 @@
+//! Plays games without a browser, from a seed and a script of inputs, and prints how they ended.
+//!
+//! The script is a file with inputs written as in replays (e.g. `0l12L0h18w`, whitespace is
+//! ignored), or a whole replay, which also gives the seed and settings of the game.
+
+use bad_tetris_core::board;
+use bad_tetris_core::game;
+use bad_tetris_core::simulation;
+use std::ops::Range;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: headless [options] SCRIPT
+
+options:
+    --seed N          seed of the first game (default 0)
+    --games N         number of games, with consecutive seeds (default 1)
+    --frames N        frames after which a game is stopped (default 1 hour of play)
+    --randomizer NAME randomizer of the games (uniform, 7-bag, 14-bag, history)
+    --das N           Delayed Auto Shift in frames
+    --arr N           Auto Repeat Rate in frames
+    --sdf N           soft drop factor (at most 1200, 20G)
+    --loop            play the script again from the start when it ends
+    --ansi            print the boards with ANSI colors";
+
+fn main() -> ExitCode {
+    let (options, seeds) = match options() {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut total_score = 0;
+    let mut total_lines = 0;
+    for seed in seeds {
+        let mut game = game::Game::new(seed, options.settings);
+        let stats = simulation::play(&mut game, &options);
+        println!(
+            "{}",
+            board::Dump {
+                board: game.board(),
+                loose_blocks: false,
+                ansi: options.ansi,
+            }
+        );
+        println!(
+            "seed: {}, frames: {}, score: {}, level: {}, lines: {}, tetrominos: {}, end: {}",
+            seed,
+            stats.frames,
+            stats.score,
+            stats.level,
+            stats.lines,
+            stats.tetrominos,
+            match stats.top_out {
+                Some(top_out) => format!("{:?}", top_out),
+                None => "frame limit".to_string(),
+            }
+        );
+        total_score += stats.score;
+        total_lines += stats.lines as u64;
+    }
+    if options.games > 1 {
+        println!(
+            "games: {}, average score: {}, average lines: {}",
+            options.games,
+            total_score / options.games,
+            total_lines / options.games
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+/// Reads the options and the script they name, and returns them with the seeds of the games
+fn options() -> Result<(simulation::Options, Range<u64>), String> {
+    let mut options = simulation::Options::parse(std::env::args().skip(1).collect())?;
+    let text = std::fs::read_to_string(&options.script)
+        .map_err(|error| format!("cannot read {}: {}", options.script, error))?;
+    options.read_script(&text)?;
+    let seeds = options.seeds()?;
+    Ok((options, seeds))
+}
@@ -0,0 +1,1142 @@
+use crate::randomizer::Randomizer;
+use crate::rotation;
+use crate::rotation::RotationSystem;
+use crate::tetrominos;
+use core::fmt;
+
+pub type Block = Option<Color>;
+
+/// Index of the T in `Board::TETROMINOS`
+const T: usize = 2;
+
+pub struct Board<const WIDTH: usize, const HEIGHT: usize> {
+    cells: [[Block; WIDTH]; HEIGHT],
+    tetromino: Option<TetrominoPosition>,
+    randomizer: Box<dyn Randomizer>,
+    rotation_system: Box<dyn RotationSystem>,
+    events: Vec<Event>,
+    top_out: Option<TopOut>,
+    held: Option<usize>,
+    // the held tetromino can be swapped only once per spawn
+    can_hold: bool,
+    // upcoming tetrominos, may be longer than the preview length
+    queue: Vec<usize>,
+    preview_length: usize,
+    // last rotation, while it is the last successful action on the tetromino
+    last_rotation: Option<Rotation>,
+    // detect spins of all tetrominos, not only T-spins
+    all_spins: bool,
+    // number of tetrominos taken from the randomizer, to bring a new one to the same state
+    draws: u64,
+}
+
+/// What is needed to save a board and restore it later, with a randomizer created the same way
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot<const WIDTH: usize, const HEIGHT: usize> {
+    pub cells: [[Block; WIDTH]; HEIGHT],
+    pub tetromino: Option<TetrominoPosition>,
+    pub held: Option<usize>,
+    pub can_hold: bool,
+    pub queue: Vec<usize>,
+    pub draws: u64,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Snapshot<WIDTH, HEIGHT> {
+    /// Checks if the falling tetromino, if any, is inside the walls and above the ground without
+    /// overlapping blocks
+    pub fn tetromino_fits(&self) -> bool {
+        self.tetromino.as_ref().is_none_or(|tetromino| {
+            Board::<WIDTH, HEIGHT>::collision(&self.cells, tetromino).is_none()
+        })
+    }
+}
+
+/// A successful rotation of the falling tetromino
+#[derive(Clone, Copy)]
+struct Rotation {
+    from: usize,
+    to: usize,
+    /// index of the kick used among the ones of the rotation system
+    kick: usize,
+}
+
+/// Something that happened on the board and that the game may want to react to (scoring,
+/// effects...)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Event {
+    Lock(Lock),
+    LineClear(LineClear),
+    TopOut(TopOut),
+}
+
+/// A tetromino that stopped falling and was dismantled into blocks
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lock {
+    /// index of the tetromino in `Board::TETROMINOS`
+    pub index: usize,
+    /// set when the tetromino was rotated into its final position
+    pub spin: Option<Spin>,
+}
+
+/// A tetromino locked right after being rotated into a tight spot
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Spin {
+    /// T-spin with both corners on the pointing side of the T occupied, or reached with the
+    /// kick moving it two rows
+    Full,
+    /// T-spin with a single corner on the pointing side occupied, or any other tetromino that
+    /// cannot move anymore when all spins are detected
+    Mini,
+}
+
+/// Full rows removed from the board at once
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LineClear {
+    /// number of rows removed
+    pub rows: usize,
+    /// true when the rows were completed by loose blocks settling (cascade) rather than directly
+    /// by a tetromino locking
+    pub chain: bool,
+    /// true when no block is left on the board after the rows were removed
+    pub perfect_clear: bool,
+}
+
+/// Reason why the game cannot continue
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TopOut {
+    /// a new tetromino spawned over existing blocks
+    BlockOut,
+    /// a tetromino locked entirely above the visible field
+    LockOut,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TetrominoPosition {
+    pub index: usize,
+    pub col: isize, // isize because tetromino position can be negative when adgacent to the left wall
+    pub row: isize,
+    pub orientation: usize,
+}
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Color {
+    Cyan,
+    Blue,
+    Magenta,
+    Yellow,
+    Orange,
+    Green,
+    Red,
+}
+
+/// Letter of each color in text representations of the board: the tetromino of that color
+const COLOR_LETTERS: [(Color, char); 7] = [
+    (Color::Cyan, 'I'),
+    (Color::Blue, 'O'),
+    (Color::Magenta, 'T'),
+    (Color::Yellow, 'L'),
+    (Color::Orange, 'J'),
+    (Color::Green, 'S'),
+    (Color::Red, 'Z'),
+];
+
+/// Letter of empty cells in the text of a board
+const EMPTY_LETTER: char = '.';
+
+impl Color {
+    pub fn letter(self) -> char {
+        COLOR_LETTERS
+            .iter()
+            .find(|(color, _)| *color == self)
+            .map(|(_, letter)| *letter)
+            .unwrap()
+    }
+
+    pub fn from_letter(letter: char) -> Option<Color> {
+        COLOR_LETTERS
+            .iter()
+            .find(|(_, color_letter)| *color_letter == letter)
+            .map(|(color, _)| *color)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Board<WIDTH, HEIGHT> {
+    pub fn new(
+        cells: [[Block; WIDTH]; HEIGHT],
+        randomizer: impl Randomizer + 'static,
+    ) -> Board<WIDTH, HEIGHT> {
+        let mut board = Board::with_empty_queue(cells, Box::new(randomizer));
+        board.fill_queue();
+        return board;
+    }
+
+    fn with_empty_queue(
+        cells: [[Block; WIDTH]; HEIGHT],
+        randomizer: Box<dyn Randomizer>,
+    ) -> Board<WIDTH, HEIGHT> {
+        Board::<WIDTH, HEIGHT> {
+            cells: cells,
+            tetromino: None,
+            randomizer: randomizer,
+            rotation_system: Box::new(rotation::Simple),
+            events: vec![],
+            top_out: None,
+            held: None,
+            can_hold: true,
+            queue: vec![],
+            preview_length: Board::<WIDTH, HEIGHT>::DEFAULT_PREVIEW_LENGTH,
+            last_rotation: None,
+            all_spins: false,
+            draws: 0,
+        }
+    }
+
+    /// Recreates a saved board. The randomizer must be created as the one of the saved board was
+    /// (same kind and seed): it is brought to the same state by drawing as many tetrominos
+    pub fn restore(
+        snapshot: Snapshot<WIDTH, HEIGHT>,
+        mut randomizer: impl Randomizer + 'static,
+    ) -> Board<WIDTH, HEIGHT> {
+        for _ in 0..snapshot.draws {
+            randomizer.next();
+        }
+        let mut board = Board::with_empty_queue(snapshot.cells, Box::new(randomizer));
+        board.tetromino = snapshot.tetromino;
+        board.held = snapshot.held;
+        board.can_hold = snapshot.can_hold;
+        board.queue = snapshot.queue;
+        board.draws = snapshot.draws;
+        board.fill_queue();
+        board
+    }
+
+    pub fn snapshot(&self) -> Snapshot<WIDTH, HEIGHT> {
+        Snapshot {
+            cells: self.cells,
+            tetromino: self.tetromino.clone(),
+            held: self.held,
+            can_hold: self.can_hold,
+            queue: self.queue.clone(),
+            draws: self.draws,
+        }
+    }
+
+    /// Writes the board one row per line from top to bottom, with `.` for empty cells, the
+    /// uppercase letter of the color of each block (see `Color::letter`) and the lowercase
+    /// letter for the blocks of the falling tetromino. Parts of the tetromino above the field are
+    /// not written
+    pub fn to_text(&self) -> String {
+        let mut rows = vec![];
+        for (row, blocks) in self.cells().iter().enumerate() {
+            let mut text = String::new();
+            for (col, block) in blocks.iter().enumerate() {
+                text.push(match block {
+                    None => EMPTY_LETTER,
+                    Some(color) if self.is_tetromino_block(row, col) => {
+                        color.letter().to_ascii_lowercase()
+                    }
+                    Some(color) => color.letter(),
+                });
+            }
+            rows.push(text);
+        }
+        rows.join("\n")
+    }
+
+    /// Reads a board written by `to_text`. Blank lines around the rows and spaces around each
+    /// row are ignored, so that boards can be written as indented string literals. When several
+    /// orientations of the falling tetromino have the same blocks, the first one is used
+    pub fn parse(
+        text: &str,
+        randomizer: impl Randomizer + 'static,
+    ) -> Result<Board<WIDTH, HEIGHT>, String> {
+        let rows: Vec<&str> = text.trim().lines().map(|row| row.trim()).collect();
+        if rows.len() != HEIGHT {
+            return Err(format!("expected {} rows, got {}", HEIGHT, rows.len()));
+        }
+        let mut cells = [[None; WIDTH]; HEIGHT];
+        let mut tetromino_letter = None;
+        let mut tetromino_blocks = vec![];
+        for (row, text) in rows.iter().enumerate() {
+            if text.chars().count() != WIDTH {
+                return Err(format!("expected {} columns in row {}", WIDTH, row));
+            }
+            for (col, letter) in text.chars().enumerate() {
+                if letter == EMPTY_LETTER {
+                    continue;
+                }
+                if letter.is_ascii_lowercase() {
+                    if tetromino_letter.is_some_and(|tetromino_letter| tetromino_letter != letter) {
+                        return Err(format!("several falling tetrominos: {}", letter));
+                    }
+                    tetromino_letter = Some(letter);
+                    tetromino_blocks.push((row as isize, col as isize));
+                } else {
+                    cells[row][col] = Some(
+                        Color::from_letter(letter).ok_or(format!("unknown color: {}", letter))?,
+                    );
+                }
+            }
+        }
+        let mut board = Board::new(cells, randomizer);
+        if let Some(letter) = tetromino_letter {
+            let color = Color::from_letter(letter.to_ascii_uppercase())
+                .ok_or(format!("unknown color: {}", letter))?;
+            let index = Board::<WIDTH, HEIGHT>::TETROMINOS
+                .iter()
+                .position(|(_, tetromino_color)| *tetromino_color == color)
+                .unwrap();
+            board.tetromino = Some(
+                Board::<WIDTH, HEIGHT>::find_tetromino(index, &tetromino_blocks)
+                    .ok_or(format!("invalid falling tetromino: {}", letter))?,
+            );
+        }
+        Ok(board)
+    }
+
+    /// Returns the position of a tetromino covering exactly the given blocks, sorted by row and
+    /// column
+    fn find_tetromino(index: usize, blocks: &[(isize, isize)]) -> Option<TetrominoPosition> {
+        let (first_row, first_col) = *blocks.first()?;
+        for orientation in 0..4 {
+            let shape = &Board::<WIDTH, HEIGHT>::TETROMINOS[index].0[orientation];
+            let shape_blocks: Vec<(isize, isize)> = (0..4)
+                .flat_map(|row| (0..4).map(move |col| (row, col)))
+                .filter(|(row, col)| shape[*row as usize][*col as usize])
+                .collect();
+            // the first block of the shape is the first block of the tetromino
+            let (row, col) = (first_row - shape_blocks[0].0, first_col - shape_blocks[0].1);
+            let tetromino_blocks: Vec<(isize, isize)> = shape_blocks
+                .iter()
+                .map(|(shape_row, shape_col)| (row + shape_row, col + shape_col))
+                .collect();
+            if tetromino_blocks == *blocks {
+                return Some(TetrominoPosition {
+                    index: index,
+                    col: col,
+                    row: row,
+                    orientation: orientation,
+                });
+            }
+        }
+        None
+    }
+
+    /// Checks if a cell is covered by the falling tetromino
+    fn is_tetromino_block(&self, row: usize, col: usize) -> bool {
+        let Some(tetromino) = &self.tetromino else {
+            return false;
+        };
+        let shape = &Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation];
+        let t_row = row as isize - tetromino.row;
+        let t_col = col as isize - tetromino.col;
+        (0..4).contains(&t_row) && (0..4).contains(&t_col) && shape[t_row as usize][t_col as usize]
+    }
+
+    const DEFAULT_PREVIEW_LENGTH: usize = 5;
+
+    const TETROMINOS: [([[[bool; 4]; 4]; 4], Color); 7] = [
+        (tetrominos::TETROMINO_I, Color::Cyan),
+        (tetrominos::TETROMINO_O, Color::Blue),
+        (tetrominos::TETROMINO_T, Color::Magenta),
+        (tetrominos::TETROMINO_L, Color::Yellow),
+        (tetrominos::TETROMINO_J, Color::Orange),
+        (tetrominos::TETROMINO_S, Color::Green),
+        (tetrominos::TETROMINO_Z, Color::Red),
+    ];
+
+    pub fn cells(&self) -> [[Block; WIDTH]; HEIGHT] {
+        if let Some(tetromino) = &self.tetromino {
+            Board::<WIDTH, HEIGHT>::add_tetromino(
+                self.cells,
+                tetromino.row,
+                tetromino.col,
+                (
+                    Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation],
+                    Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].1,
+                ),
+            )
+        } else {
+            self.cells.clone()
+        }
+    }
+
+    /// Returns the events that happened since the last call and forgets them
+    pub fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Returns why the game is over, or None while it can continue
+    pub fn top_out(&self) -> Option<TopOut> {
+        self.top_out
+    }
+
+    /// Moves everything one row down, or spawns a new tetromino when nothing is falling: one tick
+    /// of a game where the tetromino and the blocks fall at the same speed
+    #[cfg(test)]
+    pub fn advance(&mut self) {
+        // nothing moves anymore once the game is over
+        if self.top_out.is_some() {
+            return;
+        }
+        if self.falling_blocks() || self.falling_tetromino() {
+            self.down();
+        } else {
+            self.spawn_tetromino();
+        }
+    }
+
+    /// Moves the falling tetromino one row down. Returns false if it is on the ground: locking it
+    /// is up to the caller
+    pub fn fall(&mut self) -> bool {
+        if self.top_out.is_some() {
+            return false;
+        }
+        self.tetromino_down()
+    }
+
+    /// Checks if the falling tetromino cannot move down anymore
+    pub fn is_grounded(&self) -> bool {
+        match &self.tetromino {
+            Some(tetromino) => {
+                let mut new_tetromino_position = tetromino.clone();
+                new_tetromino_position.row += 1;
+                self.tetromino_is_colliding(&new_tetromino_position)
+                    .is_some()
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the lowest row covered by the falling tetromino
+    pub fn tetromino_bottom(&self) -> Option<isize> {
+        let tetromino = self.tetromino.as_ref()?;
+        let shape = &Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation];
+        let t_row = shape
+            .iter()
+            .rposition(|row| row.iter().any(|block| *block))?;
+        Some(tetromino.row + t_row as isize)
+    }
+
+    /// Dismantles the falling tetromino into blocks where it is
+    pub fn lock(&mut self) {
+        if self.top_out.is_some() {
+            return;
+        }
+        self.dismantle_tetromino();
+    }
+
+    /// Moves the falling tetromino one row down on the player's request. Returns the number of
+    /// rows the tetromino moved (0 when it is on the ground)
+    pub fn soft_drop(&mut self) -> usize {
+        if self.fall() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Moves the falling tetromino as far down as possible and dismantles it immediately. Returns
+    /// the number of rows the tetromino moved
+    pub fn hard_drop(&mut self) -> usize {
+        if self.top_out.is_some() {
+            return 0;
+        }
+        if let Some(tetromino) = &self.tetromino {
+            let landing_position = self.landing_position(tetromino);
+            let rows = (landing_position.row - tetromino.row) as usize;
+            if rows > 0 {
+                self.last_rotation = None;
+            }
+            self.tetromino = Some(landing_position);
+            self.dismantle_tetromino();
+            return rows;
+        }
+        0
+    }
+
+    /// Returns the lowest position a tetromino can reach by moving straight down
+    fn landing_position(&self, tetromino: &TetrominoPosition) -> TetrominoPosition {
+        let mut landing_position = tetromino.clone();
+        loop {
+            let mut new_position = landing_position.clone();
+            new_position.row += 1;
+            if self.tetromino_is_colliding(&new_position).is_some() {
+                return landing_position;
+            }
+            landing_position = new_position;
+        }
+    }
+
+    /// Returns the cells where the blocks of the falling tetromino end up if it is hard dropped
+    /// now, in the tetromino's color. Loose blocks still falling below it keep falling after the
+    /// drop and carry the tetromino's blocks with them, so the cells are the ones of the
+    /// tetromino's blocks once everything has settled (rows cleared on the way are not taken into
+    /// account)
+    pub fn ghost(&self) -> [[Block; WIDTH]; HEIGHT] {
+        let mut ghost = [[None; WIDTH]; HEIGHT];
+        if let Some(tetromino) = &self.tetromino {
+            let landing_position = self.landing_position(tetromino);
+            let (shape, color) = Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index];
+            // tell the tetromino's blocks apart from the other blocks
+            let mut blocks = self.cells.map(|row| row.map(|cell| cell.map(|_| false)));
+            let landed = Board::<WIDTH, HEIGHT>::add_tetromino(
+                [[None; WIDTH]; HEIGHT],
+                landing_position.row,
+                landing_position.col,
+                (shape[landing_position.orientation], color),
+            );
+            for (row, landed_row) in landed.iter().enumerate() {
+                for (col, cell) in landed_row.iter().enumerate() {
+                    if cell.is_some() {
+                        blocks[row][col] = Some(true);
+                    }
+                }
+            }
+            // blocks fall as long as there is an empty cell below them: once settled, each column
+            // keeps its blocks in the same order, packed at the bottom
+            for col in 0..WIDTH {
+                let mut settled_row = HEIGHT;
+                for row in (0..HEIGHT).rev() {
+                    if let Some(is_tetromino) = blocks[row][col] {
+                        settled_row -= 1;
+                        if is_tetromino {
+                            ghost[settled_row][col] = Some(color);
+                        }
+                    }
+                }
+            }
+        }
+        ghost
+    }
+
+    /// Moves the loose blocks one row down, independently of the falling tetromino
+    pub fn cascade(&mut self) {
+        if self.top_out.is_some() {
+            return;
+        }
+        self.blocks_down();
+    }
+
+    /// Spawns a new tetromino once the previous one has been dismantled and all blocks have
+    /// settled. Returns true if a tetromino spawned
+    pub fn try_spawn(&mut self) -> bool {
+        if self.top_out.is_some() || self.falling_blocks() || self.falling_tetromino() {
+            return false;
+        }
+        self.spawn_tetromino();
+        return true;
+    }
+
+    fn falling_blocks(&self) -> bool {
+        for row in 0..HEIGHT {
+            for col in 0..WIDTH {
+                if self.cells[row][col] != None && self.is_falling(row, col) {
+                    return true;
+                }
+            }
+        }
+        return false;
+    }
+
+    fn falling_tetromino(&self) -> bool {
+        match self.tetromino {
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Returns the index in `TETROMINOS` of the held tetromino
+    pub fn held(&self) -> Option<usize> {
+        self.held
+    }
+
+    /// Returns the shape (in spawn orientation) and the color of a tetromino, to display it
+    /// outside of the board
+    pub fn preview(index: usize) -> ([[bool; 4]; 4], Color) {
+        (
+            Board::<WIDTH, HEIGHT>::TETROMINOS[index].0[0],
+            Board::<WIDTH, HEIGHT>::TETROMINOS[index].1,
+        )
+    }
+
+    /// Puts the falling tetromino aside and replaces it with the previously held one, or with a
+    /// new one if nothing was held. Can only be done once until the next tetromino spawns.
+    /// Returns true if the tetromino was swapped
+    pub fn hold(&mut self) -> bool {
+        if self.top_out.is_some() || !self.can_hold {
+            return false;
+        }
+        if let Some(tetromino) = self.tetromino.take() {
+            let index = match self.held.replace(tetromino.index) {
+                Some(index) => index,
+                None => self.next_index(),
+            };
+            self.place_tetromino(index);
+            self.can_hold = false;
+            return true;
+        }
+        false
+    }
+
+    /// Returns the indexes in `TETROMINOS` of the next tetrominos to spawn, first to spawn first
+    pub fn queue(&self) -> &[usize] {
+        &self.queue[..self.preview_length]
+    }
+
+    /// Changes the number of upcoming tetrominos returned by `queue`. The order in which
+    /// tetrominos spawn does not depend on it
+    pub fn set_preview_length(&mut self, preview_length: usize) {
+        self.preview_length = preview_length;
+        self.fill_queue();
+    }
+
+    fn next_index(&mut self) -> usize {
+        let index = self.queue.remove(0);
+        self.fill_queue();
+        index
+    }
+
+    fn fill_queue(&mut self) {
+        // keep at least one tetromino so that the next one can always be taken from the queue
+        while self.queue.len() < self.preview_length.max(1) {
+            let index = self.randomizer.next();
+            self.draws += 1;
+            self.queue.push(index);
+        }
+    }
+
+    fn spawn_tetromino(&mut self) {
+        let index = self.next_index();
+        self.place_tetromino(index);
+        self.can_hold = true;
+    }
+
+    /// Places a tetromino at the top of the board
+    fn place_tetromino(&mut self, index: usize) {
+        let t_width = Board::<WIDTH, HEIGHT>::TETROMINOS[index].0[0][0].len();
+        let start = WIDTH / 2 - t_width / 2;
+        let tetromino = TetrominoPosition {
+            index: index,
+            col: start as isize,
+            row: 0,
+            orientation: 0,
+        };
+        if self.tetromino_is_colliding(&tetromino).is_some() {
+            self.set_top_out(TopOut::BlockOut);
+        }
+        // the tetromino is kept even when overlapping blocks so that the block out is visible
+        self.tetromino = Some(tetromino);
+        self.last_rotation = None;
+    }
+
+    fn set_top_out(&mut self, top_out: TopOut) {
+        self.top_out = Some(top_out);
+        self.events.push(Event::TopOut(top_out));
+    }
+
+    fn add_tetromino<const TETROMINO_WIDTH: usize, const TETROMINO_HEIGHT: usize>(
+        cells: [[Block; WIDTH]; HEIGHT],
+        row: isize,
+        col: isize,
+        tetromino: ([[bool; TETROMINO_WIDTH]; TETROMINO_HEIGHT], Color),
+    ) -> [[Block; WIDTH]; HEIGHT] {
+        let mut result = cells.clone();
+        for t_row in 0..TETROMINO_HEIGHT {
+            for t_col in 0..TETROMINO_WIDTH {
+                let b_row = row + t_row as isize;
+                let b_col = col + t_col as isize;
+                if b_row >= 0 && b_col >= 0 && b_row < HEIGHT as isize && b_col < WIDTH as isize {
+                    let (t_values, t_color) = tetromino;
+                    if t_values[t_row][t_col] {
+                        result[b_row as usize][b_col as usize] = Some(t_color);
+                    }
+                }
+            }
+        }
+        return result;
+    }
+
+    #[cfg(test)]
+    fn down(&mut self) {
+        // without lock delay, the tetromino is dismantled as soon as it cannot move down
+        if !self.tetromino_down() {
+            self.dismantle_tetromino();
+        }
+        self.blocks_down();
+    }
+
+    /// Moves the falling tetromino one row down if possible, returns true if it moved
+    fn tetromino_down(&mut self) -> bool {
+        if let Some(tetromino) = &self.tetromino {
+            let mut new_tetromino_position = tetromino.clone();
+            new_tetromino_position.row += 1;
+            if self
+                .tetromino_is_colliding(&new_tetromino_position)
+                .is_none()
+            {
+                self.tetromino = Some(new_tetromino_position);
+                self.last_rotation = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn dismantle_tetromino(&mut self) {
+        if let Some(tetromino) = self.tetromino.take() {
+            let lock_out = Board::<WIDTH, HEIGHT>::is_above_field(&tetromino);
+            let spin = self.spin(&tetromino);
+            self.events.push(Event::Lock(Lock {
+                index: tetromino.index,
+                spin: spin,
+            }));
+            self.cells = Board::add_tetromino(
+                self.cells,
+                tetromino.row as isize,
+                tetromino.col as isize,
+                (
+                    Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation],
+                    Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].1,
+                ),
+            );
+            if lock_out {
+                self.set_top_out(TopOut::LockOut);
+            } else {
+                self.clear_lines(false);
+            }
+        }
+    }
+
+    /// Detects if a tetromino about to lock was spun into its position
+    fn spin(&self, tetromino: &TetrominoPosition) -> Option<Spin> {
+        if self.last_rotation.is_none() {
+            return None;
+        }
+        if tetromino.index == T {
+            self.t_spin(tetromino)
+        } else if self.all_spins && self.is_immobile(tetromino) {
+            Some(Spin::Mini)
+        } else {
+            None
+        }
+    }
+
+    /// Three-corner rule: at least three of the cells diagonal to the center of the T are
+    /// occupied (by blocks or out of the board)
+    fn t_spin(&self, tetromino: &TetrominoPosition) -> Option<Spin> {
+        // corners around the center of the T in its 4x4 grid, clockwise from the top left
+        const CORNERS: [(isize, isize); 4] = [(0, 1), (0, 3), (2, 3), (2, 1)];
+        let occupied = CORNERS.map(|(row, col)| {
+            let b_row = tetromino.row + row;
+            let b_col = tetromino.col + col;
+            if b_col < 0 || b_col >= WIDTH as isize || b_row >= HEIGHT as isize {
+                true
+            } else if b_row < 0 {
+                false
+            } else {
+                self.cells[b_row as usize][b_col as usize].is_some()
+            }
+        });
+        if occupied.iter().filter(|occupied| **occupied).count() < 3 {
+            return None;
+        }
+        // the T points towards the corners `orientation` and `orientation + 1`
+        let front = occupied[tetromino.orientation] && occupied[(tetromino.orientation + 1) % 4];
+        // some kicks are only possible into a slot surrounded by blocks
+        let t_spin_kick = self.last_rotation.is_some_and(|rotation| {
+            self.rotation_system
+                .is_t_spin_kick(rotation.from, rotation.to, rotation.kick)
+        });
+        if front || t_spin_kick {
+            Some(Spin::Full)
+        } else {
+            Some(Spin::Mini)
+        }
+    }
+
+    /// Checks if a tetromino can move neither left, right, up nor down
+    fn is_immobile(&self, tetromino: &TetrominoPosition) -> bool {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)].iter().all(|(row, col)| {
+            let mut moved_position = tetromino.clone();
+            moved_position.row += row;
+            moved_position.col += col;
+            self.tetromino_is_colliding(&moved_position).is_some()
+        })
+    }
+
+    /// Checks if all the blocks of a tetromino are above the top of the board
+    fn is_above_field(tetromino: &TetrominoPosition) -> bool {
+        let current_tetromino =
+            &Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation];
+        for (t_row, blocks) in current_tetromino.iter().enumerate() {
+            if blocks.iter().any(|&block| block) && tetromino.row + t_row as isize >= 0 {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    fn blocks_down(&mut self) {
+        // move all blocks one cell down if the cell bellow is empty
+        // iterate through cells from bottom to top to avoid collisions
+        let second_to_last_line = HEIGHT - 2; // ignore the most bottom line: blocks on this line cannot fall further
+        let first_line = 0;
+        for row in (first_line..=second_to_last_line).rev() {
+            for col in 0..WIDTH {
+                let current_cell_empty = self.cells[row][col] == None;
+                let bellow_cell_empty = self.cells[row + 1][col] == None;
+
+                if !current_cell_empty && bellow_cell_empty {
+                    self.cells[row + 1][col] = self.cells[row][col];
+                    self.cells[row][col] = None;
+                }
+            }
+        }
+        // settling blocks can complete rows
+        self.clear_lines(true);
+    }
+
+    fn clear_lines(&mut self, chain: bool) {
+        let mut rows = 0;
+        for row in 0..HEIGHT {
+            if self.is_full_row(row) {
+                // only empty the row: blocks above it are now falling and will cascade down
+                self.cells[row] = [None; WIDTH];
+                rows += 1;
+            }
+        }
+        if rows > 0 {
+            self.events.push(Event::LineClear(LineClear {
+                rows: rows,
+                chain: chain,
+                perfect_clear: self.cells.iter().flatten().all(|cell| cell.is_none()),
+            }));
+        }
+    }
+
+    fn is_full_row(&self, row: usize) -> bool {
+        // a row is full when it has no empty cell and none of its blocks is still falling
+        for col in 0..WIDTH {
+            if self.cells[row][col] == None || self.is_falling(row, col) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /// Moves the falling tetromino and the falling blocks to the left. Returns true if the
+    /// tetromino moved
+    pub fn left(&mut self) -> bool {
+        let moved = self.tetromino_left();
+        self.blocks_left();
+        moved
+    }
+
+    fn tetromino_left(&mut self) -> bool {
+        if let Some(tetromino) = &self.tetromino {
+            let mut new_tetromino_position = tetromino.clone();
+            new_tetromino_position.col -= 1;
+            if self
+                .tetromino_is_colliding(&new_tetromino_position)
+                .is_none()
+            {
+                self.tetromino = Some(new_tetromino_position);
+                self.last_rotation = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn blocks_left(&mut self) {
+        for row in 0..HEIGHT {
+            for col in 0..WIDTH {
+                // don't move cells on the first column
+                if col != 0 {
+                    // move only falling cells
+                    if self.is_falling(row, col) {
+                        // if the current cell is not empty and the left cell is
+                        if self.cells[row][col] != None && self.cells[row][col - 1] == None {
+                            // move the cell to the left
+                            self.cells[row][col - 1] = self.cells[row][col];
+                            self.cells[row][col] = None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the falling tetromino and the falling blocks to the right. Returns true if the
+    /// tetromino moved
+    pub fn right(&mut self) -> bool {
+        let moved = self.tetromino_right();
+        self.blocks_right();
+        moved
+    }
+
+    fn tetromino_right(&mut self) -> bool {
+        if let Some(tetromino) = &self.tetromino {
+            let mut new_tetromino_position = tetromino.clone();
+            new_tetromino_position.col += 1;
+            if self
+                .tetromino_is_colliding(&new_tetromino_position)
+                .is_none()
+            {
+                self.tetromino = Some(new_tetromino_position);
+                self.last_rotation = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn blocks_right(&mut self) {
+        for row in 0..HEIGHT {
+            for col in (0..WIDTH).rev() {
+                // don't move cells on the last column
+                if col != WIDTH - 1 {
+                    // move only falling cells
+                    if self.is_falling(row, col) {
+                        // if the current cell is not empty and the left cell is
+                        if self.cells[row][col] != None && self.cells[row][col + 1] == None {
+                            // move the cell to the left
+                            self.cells[row][col + 1] = self.cells[row][col];
+                            self.cells[row][col] = None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reports spins of every tetromino that ends up unable to move after a rotation, as mini
+    /// spins, in addition to T-spins
+    pub fn set_all_spins(&mut self, all_spins: bool) {
+        self.all_spins = all_spins;
+    }
+
+    /// Changes how tetrominos are kicked when rotated into blocks or walls
+    pub fn set_rotation_system(&mut self, rotation_system: impl RotationSystem + 'static) {
+        self.rotation_system = Box::new(rotation_system);
+    }
+
+    /// Rotates the falling tetromino clockwise. Returns true if it rotated
+    pub fn rotate(&mut self) -> bool {
+        self.rotate_by(1)
+    }
+
+    pub fn rotate_counterclockwise(&mut self) -> bool {
+        self.rotate_by(3)
+    }
+
+    pub fn rotate_180(&mut self) -> bool {
+        self.rotate_by(2)
+    }
+
+    /// Rotates the falling tetromino by a number of clockwise quarter turns
+    fn rotate_by(&mut self, quarter_turns: usize) -> bool {
+        if let Some(tetromino) = &self.tetromino {
+            let mut new_tetromino_position = tetromino.clone();
+            new_tetromino_position.orientation =
+                (new_tetromino_position.orientation + quarter_turns) % 4;
+
+            // forbid rotations on the ground if it makes the tetromino collide with it, unless the
+            // rotation system can kick it up
+            if let Some(Collision::Ground) = self.tetromino_is_colliding(&new_tetromino_position) {
+                if !self.rotation_system.kicks_off_the_ground() {
+                    return false;
+                }
+            }
+
+            // try the kicks of the rotation system until the tetromino does not collide
+            let kicks = self.rotation_system.kicks(
+                tetromino.index,
+                tetromino.orientation,
+                new_tetromino_position.orientation,
+            );
+            for (kick, (col, row)) in kicks.into_iter().enumerate() {
+                let mut kicked_position = new_tetromino_position.clone();
+                kicked_position.col += col;
+                kicked_position.row += row;
+                if self.tetromino_is_colliding(&kicked_position).is_none() {
+                    self.last_rotation = Some(Rotation {
+                        from: tetromino.orientation,
+                        to: kicked_position.orientation,
+                        kick: kick,
+                    });
+                    self.tetromino = Some(kicked_position);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn is_falling(&self, row: usize, col: usize) -> bool {
+        // a block is falling if there is at least one empty cell bellow it
+        for row in (row + 1)..HEIGHT {
+            if self.cells[row][col] == None {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    /// Checks if a tetromino collides with a block or the board's boundaries
+    fn tetromino_is_colliding(&self, tetromino: &TetrominoPosition) -> Option<Collision> {
+        Board::<WIDTH, HEIGHT>::collision(&self.cells, tetromino)
+    }
+
+    fn collision(
+        cells: &[[Block; WIDTH]; HEIGHT],
+        tetromino: &TetrominoPosition,
+    ) -> Option<Collision> {
+        let current_tetromino =
+            &Board::<WIDTH, HEIGHT>::TETROMINOS[tetromino.index].0[tetromino.orientation];
+        let t_width = current_tetromino[0].len();
+        let t_height = current_tetromino.len();
+        // iterate through the tetromino at the given position and the board under it
+        for t_col in 0..t_width {
+            for t_row in 0..t_height {
+                if current_tetromino[t_row][t_col] {
+                    let b_col = tetromino.col + t_col as isize;
+                    let b_row = tetromino.row + t_row as isize;
+                    if b_col < 0 || b_col >= WIDTH as isize {
+                        return Some(Collision::Wall);
+                    } else if b_row >= HEIGHT as isize {
+                        return Some(Collision::Ground);
+                    } else if b_row < 0 {
+                        // do nothing, tetromino can cross top of board
+                    } else if cells[b_row as usize][b_col as usize].is_some() {
+                        return Some(Collision::Block);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+enum Collision {
+    Block,
+    Wall,
+    Ground,
+}
+
+/// Picture of a board for debugging: the text of the board (see `Board::to_text`) in a border
+pub struct Dump<'a, const WIDTH: usize, const HEIGHT: usize> {
+    pub board: &'a Board<WIDTH, HEIGHT>,
+    /// write blocks that are still falling down as `*`
+    pub loose_blocks: bool,
+    /// color the blocks with ANSI escape codes, for terminals
+    pub ansi: bool,
+}
+
+impl Color {
+    /// ANSI escape code setting the background to the color
+    fn ansi_background(self) -> &'static str {
+        match self {
+            Color::Cyan => "\x1b[46m",
+            Color::Blue => "\x1b[44m",
+            Color::Magenta => "\x1b[45m",
+            Color::Yellow => "\x1b[43m",
+            Color::Orange => "\x1b[48;5;208m",
+            Color::Green => "\x1b[42m",
+            Color::Red => "\x1b[41m",
+        }
+    }
+}
+
+/// ANSI escape code restoring the default colors
+const ANSI_RESET: &str = "\x1b[0m";
+
+impl<const WIDTH: usize, const HEIGHT: usize> fmt::Display for Dump<'_, WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let border = format!("+{}+", "-".repeat(WIDTH));
+        writeln!(f, "{}", border)?;
+        for (row, blocks) in self.board.cells().iter().enumerate() {
+            write!(f, "|")?;
+            for (col, block) in blocks.iter().enumerate() {
+                let Some(color) = block else {
+                    write!(f, "{}", EMPTY_LETTER)?;
+                    continue;
+                };
+                let letter = if self.board.is_tetromino_block(row, col) {
+                    color.letter().to_ascii_lowercase()
+                } else if self.loose_blocks && self.board.is_falling(row, col) {
+                    '*'
+                } else {
+                    color.letter()
+                };
+                match self.ansi {
+                    true => write!(f, "{}{}{}", color.ansi_background(), letter, ANSI_RESET)?,
+                    false => write!(f, "{}", letter)?,
+                }
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "{}", border)
+    }
+}
+
+/// Writes the board in a border, `{:#?}` also marks the loose blocks
+impl<const WIDTH: usize, const HEIGHT: usize> fmt::Debug for Board<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let dump = Dump {
+            board: self,
+            loose_blocks: f.alternate(),
+            ansi: false,
+        };
+        write!(f, "{}", dump)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> PartialEq for Board<WIDTH, HEIGHT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells() == other.cells()
+    }
+}
+
+#[cfg(test)]
+mod block_physics_tests;
+
+#[cfg(test)]
+mod tetromino_physics_tests;
+
+#[cfg(test)]
+mod line_clear_tests;
+
+#[cfg(test)]
+mod top_out_tests;
+
+#[cfg(test)]
+mod scoring_tests;
+
+#[cfg(test)]
+mod drop_tests;
+
+#[cfg(test)]
+mod ghost_tests;
+
+#[cfg(test)]
+mod hold_tests;
+
+#[cfg(test)]
+mod queue_tests;
+
+#[cfg(test)]
+mod srs_tests;
+
+#[cfg(test)]
+mod spin_tests;
+
+#[cfg(test)]
+mod snapshot_tests;
+
+#[cfg(test)]
+mod text_tests;
+
+#[cfg(test)]
+mod dump_tests;
@@ -0,0 +1,316 @@
+use super::*;
+use rand::prelude::*;
+
+const SEED: [u8; 32] = [0; 32];
+
+#[test]
+fn advance_moves_block_one_cell_down() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        I....
+        .....
+        .....
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        .....
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.advance();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn advance_falling_block_stops_on_bottom() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        I....
+        .....
+        .I...
+        ....I
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        .....
+        .....
+        .....
+        II..I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    for _ in 0..4 {
+        board.advance();
+    }
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn advance_falling_block_stops_on_other_block() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        I.I.I
+        ..I.I
+        .....
+        ....I
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        .....
+        ....I
+        ..I.I
+        I.I.I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    for _ in 0..4 {
+        board.advance();
+    }
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn left_moves_blocks_to_the_left() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        .I...
+        ...I.
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        ..I..
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.left();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn left_stops_at_walls() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        ...I.
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        ..I..
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.left();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn left_stops_at_other_blocks() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        II...
+        ...I.
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        II...
+        ..I..
+        .....
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.left();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn left_only_moves_falling_blocks() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        ...I.
+        ...I.
+        .I...
+        ....I
+        .I..I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        ..I..
+        ..I..
+        I....
+        ....I
+        .I..I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.left();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn right_moves_blocks_to_the_right() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        .....
+        ..I..
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        .I...
+        .....
+        ...I.
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.right();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn right_stops_at_walls() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        .....
+        ....I
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        .I...
+        .....
+        ....I
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.right();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn right_stops_at_other_blocks() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        .....
+        I....
+        .....
+        ...II
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        .....
+        .I...
+        .....
+        ...II
+        .....
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.right();
+    assert_eq!(board, expected_board);
+}
+
+#[test]
+fn right_only_moves_falling_blocks() {
+    let mut board = Board::<5, 5>::parse(
+        "
+        ..I..
+        ..I..
+        I....
+        ....I
+        I...I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    let expected_board = Board::<5, 5>::parse(
+        "
+        ...I.
+        ...I.
+        .I...
+        ....I
+        I...I
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap();
+    board.right();
+    assert_eq!(board, expected_board);
+}
@@ -0,0 +1,141 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+#[test]
+fn soft_drop_moves_tetromino_one_row_down() {
+    // given a newly spawn tetromino
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+
+    // when the player soft drops it
+    let rows = board.soft_drop();
+
+    // then it moves one row down
+    assert_eq!(rows, 1);
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn soft_drop_on_the_ground_leaves_the_tetromino_to_the_lock_delay() {
+    // given a tetromino on the ground
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+    for _ in 0..5 {
+        assert_eq!(board.soft_drop(), 1);
+    }
+    assert!(board.is_grounded());
+
+    // when the player soft drops it
+    let rows = board.soft_drop();
+
+    // then it does not move and is not locked yet
+    assert_eq!(rows, 0);
+    assert_eq!(board.take_events(), vec![]);
+    assert!(board.left());
+
+    // until the lock delay expires
+    board.lock();
+    assert_eq!(
+        board.take_events(),
+        vec![Event::Lock(Lock {
+            index: 2,
+            spin: None
+        })]
+    );
+    assert!(!board.is_grounded());
+}
+
+#[test]
+fn hard_drop_moves_tetromino_to_the_ground_and_dismantles_it() {
+    // given a newly spawn tetromino
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+
+    // when the player hard drops it
+    let rows = board.hard_drop();
+
+    // then it lands on the ground and is dismantled at once
+    assert_eq!(rows, 5);
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+    assert_eq!(
+        board.take_events(),
+        vec![Event::Lock(Lock {
+            index: 2,
+            spin: None
+        })]
+    );
+    assert_eq!(board.hard_drop(), 0);
+}
+
+#[test]
+fn hard_drop_on_a_block_lets_overhanging_blocks_cascade() {
+    // given a tetromino above a single block
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+
+    // when the player hard drops it
+    let rows = board.hard_drop();
+
+    // then it lands on the block
+    assert_eq!(rows, 4);
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, o, o, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+
+    // and the blocks that are not supported settle afterwards
+    board.cascade();
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
@@ -0,0 +1,53 @@
+use super::*;
+use rand::prelude::*;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn board() -> Board<5, 4> {
+    Board::<5, 4>::parse(
+        "
+        ..t..
+        .ttt.
+        .S...
+        I..OO
+        ",
+        rand::rngs::StdRng::from_seed(SEED),
+    )
+    .unwrap()
+}
+
+#[test]
+fn border_fits_the_width_of_the_board() {
+    let board = Board::<3, 2>::new([[None; 3]; 2], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(format!("{:?}", board), "+---+\n|...|\n|...|\n+---+");
+}
+
+#[test]
+fn blocks_are_written_with_their_color_and_the_tetromino_in_lowercase() {
+    assert_eq!(
+        format!("{:?}", board()),
+        "+-----+\n|..t..|\n|.ttt.|\n|.S...|\n|I..OO|\n+-----+"
+    );
+}
+
+#[test]
+fn alternate_format_marks_loose_blocks() {
+    assert_eq!(
+        format!("{:#?}", board()),
+        "+-----+\n|..t..|\n|.ttt.|\n|.*...|\n|I..OO|\n+-----+"
+    );
+}
+
+#[test]
+fn ansi_dump_colors_the_blocks() {
+    let board = Board::<3, 1>::parse("I.Z", rand::rngs::StdRng::from_seed(SEED)).unwrap();
+    let dump = Dump {
+        board: &board,
+        loose_blocks: false,
+        ansi: true,
+    };
+    assert_eq!(
+        dump.to_string(),
+        "+---+\n|\x1b[46mI\x1b[0m.\x1b[41mZ\x1b[0m|\n+---+"
+    );
+}
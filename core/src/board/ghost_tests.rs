@@ -0,0 +1,83 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+static R: Option<Color> = Some(Color::Red);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+#[test]
+fn ghost_is_where_the_tetromino_lands() {
+    // given a newly spawn tetromino above settled blocks
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, R, R, R, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+
+    // when the ghost is computed
+    let ghost = board.ghost();
+
+    // then it shows the tetromino resting on the blocks
+    #[rustfmt::skip]
+    assert_eq!(ghost, [
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, o, o, o, o, o,],
+    ]);
+}
+
+#[test]
+fn ghost_shows_the_tetromino_blocks_once_settled() {
+    // given a tetromino above a loose block still falling
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, R, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 0,
+        orientation: 0,
+    });
+
+    // when the ghost is computed
+    let ghost = board.ghost();
+
+    // then it shows where the tetromino's blocks will be after the block settles and the
+    // overhanging blocks cascade
+    #[rustfmt::skip]
+    assert_eq!(ghost, [
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, o, X, o, o,],
+    ]);
+}
+
+#[test]
+fn no_ghost_without_a_falling_tetromino() {
+    let board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board.ghost(), [[o; 7]; 7]);
+}
@@ -0,0 +1,66 @@
+use super::*;
+use rand::prelude::*;
+
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn falling_index<const WIDTH: usize, const HEIGHT: usize>(board: &Board<WIDTH, HEIGHT>) -> usize {
+    board.tetromino.as_ref().unwrap().index
+}
+
+#[test]
+fn holding_with_an_empty_slot_replaces_the_tetromino_with_a_new_one() {
+    // given a falling tetromino
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+    board.advance();
+    let first = falling_index(&board);
+    assert_eq!(board.held(), None);
+
+    // when the player holds it
+    assert!(board.hold());
+
+    // then it is held and a new tetromino spawns at the top
+    assert_eq!(board.held(), Some(first));
+    assert_eq!(board.tetromino.as_ref().unwrap().row, 0);
+}
+
+#[test]
+fn holding_twice_before_the_next_spawn_does_nothing() {
+    // given a tetromino that was just swapped with the hold slot
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+    let first = falling_index(&board);
+    assert!(board.hold());
+    let second = falling_index(&board);
+
+    // when the player holds again
+    assert!(!board.hold());
+
+    // then nothing changes
+    assert_eq!(board.held(), Some(first));
+    assert_eq!(falling_index(&board), second);
+}
+
+#[test]
+fn holding_after_a_spawn_swaps_with_the_held_tetromino() {
+    // given a held tetromino and a new tetromino spawning after a lock
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+    let first = falling_index(&board);
+    assert!(board.hold());
+    board.hard_drop();
+    while !board.try_spawn() {
+        board.cascade();
+    }
+    let third = falling_index(&board);
+
+    // when the player holds
+    assert!(board.hold());
+
+    // then the held tetromino comes back
+    assert_eq!(falling_index(&board), first);
+    assert_eq!(board.held(), Some(third));
+}
@@ -0,0 +1,183 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+#[test]
+fn full_row_is_removed_when_tetromino_locks() {
+    // given a T about to lock in the gap of the bottom row
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [X, X, o, o, o, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    for _ in 0..6 {
+        board.advance();
+    }
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [X, X, X, X, X, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+    assert_eq!(board.take_events(), vec![]);
+
+    // when the tetromino locks
+    board.advance();
+
+    // then the full row is removed and the block above it falls
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+    assert_eq!(
+        board.take_events(),
+        vec![
+            Event::Lock(Lock {
+                index: 2,
+                spin: None
+            }),
+            Event::LineClear(LineClear {
+                rows: 1,
+                chain: false,
+                perfect_clear: false
+            })
+        ]
+    );
+}
+
+#[test]
+fn settling_block_completing_a_row_is_a_chain() {
+    // given a loose block falling above a gap
+    #[rustfmt::skip]
+    let mut board = Board::<5, 5>::new([
+        [o, o, X, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [X, o, o, o, o,],
+        [X, X, o, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+
+    // when it settles in the gap
+    for _ in 0..4 {
+        board.advance();
+    }
+
+    // then the row is removed as a chain and the blocks above it start falling
+    #[rustfmt::skip]
+    let expected = Board::<5, 5>::new([
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [X, o, o, o, o,],
+        [o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+    assert_eq!(
+        board.take_events(),
+        vec![Event::LineClear(LineClear {
+            rows: 1,
+            chain: true,
+            perfect_clear: false
+        })]
+    );
+
+    board.advance();
+    #[rustfmt::skip]
+    let expected = Board::<5, 5>::new([
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [X, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn rows_completed_at_once_are_reported_together() {
+    // given two loose blocks falling above a two rows deep gap
+    #[rustfmt::skip]
+    let mut board = Board::<5, 5>::new([
+        [o, o, X, o, o,],
+        [o, o, X, o, o,],
+        [o, o, o, o, o,],
+        [X, X, o, X, X,],
+        [X, X, o, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+
+    // when they settle
+    for _ in 0..3 {
+        board.advance();
+    }
+
+    // then both rows are removed by a single clear, emptying the board
+    assert_eq!(
+        board,
+        Board::<5, 5>::new([[o; 5]; 5], rand::rngs::StdRng::from_seed(SEED))
+    );
+    assert_eq!(
+        board.take_events(),
+        vec![Event::LineClear(LineClear {
+            rows: 2,
+            chain: true,
+            perfect_clear: true
+        })]
+    );
+}
+
+#[test]
+fn full_row_is_only_removed_once_its_blocks_have_settled() {
+    // given a full row whose blocks are not all settled
+    #[rustfmt::skip]
+    let mut board = Board::<5, 5>::new([
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [X, X, X, X, X,],
+        [o, o, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+
+    // when the falling blocks settle
+    board.advance();
+
+    // then only the row they settled in is removed
+    #[rustfmt::skip]
+    let expected = Board::<5, 5>::new([
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, o, o, o,],
+        [o, o, X, o, o,],
+        [o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+    assert_eq!(
+        board.take_events(),
+        vec![Event::LineClear(LineClear {
+            rows: 1,
+            chain: true,
+            perfect_clear: false
+        })]
+    );
+}
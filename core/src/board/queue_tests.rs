@@ -0,0 +1,63 @@
+use super::*;
+use rand::prelude::*;
+
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+/// Spawns tetrominos one after the other and returns their indexes
+fn spawn_sequence<const WIDTH: usize, const HEIGHT: usize>(
+    board: &mut Board<WIDTH, HEIGHT>,
+    length: usize,
+) -> Vec<usize> {
+    let mut sequence = vec![];
+    for _ in 0..length {
+        assert!(board.try_spawn());
+        sequence.push(board.tetromino.take().unwrap().index);
+    }
+    sequence
+}
+
+#[test]
+fn queue_shows_the_next_tetrominos_in_spawn_order() {
+    // given a board showing the next 3 tetrominos
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.set_preview_length(3);
+    let queue = board.queue().to_vec();
+    assert_eq!(queue.len(), 3);
+
+    // when tetrominos spawn
+    let sequence = spawn_sequence(&mut board, 3);
+
+    // then they are the ones that were in the queue
+    assert_eq!(sequence, queue);
+}
+
+#[test]
+fn queue_moves_forward_when_a_tetromino_spawns() {
+    // given a board showing the next 2 tetrominos
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.set_preview_length(2);
+    let queue = board.queue().to_vec();
+
+    // when a tetromino spawns
+    spawn_sequence(&mut board, 1);
+
+    // then the second tetromino becomes the first of the queue
+    assert_eq!(board.queue().len(), 2);
+    assert_eq!(board.queue()[0], queue[1]);
+}
+
+#[test]
+fn preview_length_does_not_change_the_spawn_order() {
+    let mut short_board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    short_board.set_preview_length(1);
+    let mut long_board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    long_board.set_preview_length(6);
+
+    assert_eq!(
+        spawn_sequence(&mut short_board, 10),
+        spawn_sequence(&mut long_board, 10)
+    );
+}
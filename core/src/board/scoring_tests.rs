@@ -0,0 +1,210 @@
+use super::*;
+use crate::scoring::Scoring;
+use crate::streaks;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn line_clear(rows: usize) -> Event {
+    Event::LineClear(LineClear {
+        rows: rows,
+        chain: false,
+        perfect_clear: false,
+    })
+}
+
+#[test]
+fn line_clears_are_worth_guideline_points() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.handle(&line_clear(1));
+    assert_eq!(scoring.score(), 100);
+    scoring.handle(&line_clear(2));
+    assert_eq!(scoring.score(), 100 + 300);
+    scoring.handle(&line_clear(3));
+    assert_eq!(scoring.score(), 100 + 300 + 500);
+    scoring.handle(&line_clear(4));
+    assert_eq!(scoring.score(), 100 + 300 + 500 + 800);
+    assert_eq!(scoring.lines(), 10);
+}
+
+#[test]
+fn line_clears_are_multiplied_by_the_level() {
+    let mut scoring = Scoring::new(3, 10);
+    scoring.handle(&line_clear(4));
+    assert_eq!(scoring.score(), 3 * 800);
+}
+
+#[test]
+fn cascades_clearing_more_than_four_rows_are_worth_more_than_a_tetris() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.handle(&line_clear(6));
+    assert_eq!(scoring.score(), 800 + 2 * 200);
+}
+
+#[test]
+fn level_increases_every_lines_per_level() {
+    let mut scoring = Scoring::new(1, 5);
+    scoring.handle(&line_clear(4));
+    assert_eq!(scoring.level(), 1);
+
+    // the clear reaching the goal is scored with the previous level
+    scoring.handle(&line_clear(1));
+    assert_eq!(scoring.level(), 2);
+    assert_eq!(scoring.score(), 800 + 100);
+
+    scoring.handle(&line_clear(1));
+    assert_eq!(scoring.score(), 800 + 100 + 2 * 100);
+
+    scoring.handle(&line_clear(4));
+    assert_eq!(scoring.level(), 3);
+    assert_eq!(scoring.lines(), 10);
+}
+
+#[test]
+fn drops_are_worth_points_per_row() {
+    let mut scoring = Scoring::new(5, 10);
+    scoring.soft_drop(3);
+    assert_eq!(scoring.score(), 3);
+    scoring.hard_drop(10);
+    assert_eq!(scoring.score(), 3 + 20);
+}
+
+#[test]
+fn locks_without_clears_are_not_worth_points() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.handle(&Event::Lock(Lock {
+        index: 0,
+        spin: None,
+    }));
+    assert_eq!(scoring.score(), 0);
+}
+
+#[test]
+fn scoring_a_line_clear_from_the_board() {
+    // given a T about to lock in the gap of the bottom row
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [X, X, o, o, o, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    let mut scoring = Scoring::new(1, 10);
+
+    // when the tetromino locks and the board events are scored
+    for _ in 0..7 {
+        board.advance();
+    }
+    for event in board.take_events() {
+        scoring.handle(&event);
+    }
+
+    // then the single is scored
+    assert_eq!(scoring.score(), 100);
+    assert_eq!(scoring.lines(), 1);
+}
+
+#[test]
+fn t_spins_are_worth_points_even_without_clearing_rows() {
+    let mut scoring = Scoring::new(2, 10);
+    scoring.handle(&Event::Lock(Lock {
+        index: 2,
+        spin: Some(Spin::Full),
+    }));
+    assert_eq!(scoring.score(), 2 * 400);
+    scoring.handle(&Event::Lock(Lock {
+        index: 2,
+        spin: Some(Spin::Mini),
+    }));
+    assert_eq!(scoring.score(), 2 * 400 + 2 * 100);
+}
+
+#[test]
+fn rows_cleared_by_a_t_spin_are_worth_more() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.handle(&Event::Lock(Lock {
+        index: 2,
+        spin: Some(Spin::Full),
+    }));
+    scoring.handle(&line_clear(2));
+    assert_eq!(scoring.score(), 1200);
+
+    scoring.handle(&Event::Lock(Lock {
+        index: 2,
+        spin: Some(Spin::Mini),
+    }));
+    scoring.handle(&line_clear(1));
+    assert_eq!(scoring.score(), 1200 + 200);
+}
+
+#[test]
+fn chain_clears_after_a_t_spin_are_not_part_of_it() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.handle(&Event::Lock(Lock {
+        index: 2,
+        spin: Some(Spin::Full),
+    }));
+    scoring.handle(&line_clear(1));
+    scoring.handle(&Event::LineClear(LineClear {
+        rows: 1,
+        chain: true,
+        perfect_clear: false,
+    }));
+    assert_eq!(scoring.score(), 800 + 100);
+}
+
+fn clear(rows: usize, combo: u32, back_to_back: bool, perfect_clear: bool) -> streaks::Clear {
+    streaks::Clear {
+        rows: rows,
+        lock: Some(Lock {
+            index: 0,
+            spin: None,
+        }),
+        combo: combo,
+        back_to_back: back_to_back,
+        perfect_clear: perfect_clear,
+    }
+}
+
+#[test]
+fn combos_are_worth_50_points_per_previous_clear() {
+    let mut scoring = Scoring::new(2, 10);
+    scoring.bonus(&clear(1, 0, false, false));
+    assert_eq!(scoring.score(), 0);
+    scoring.bonus(&clear(1, 3, false, false));
+    assert_eq!(scoring.score(), 2 * 150);
+}
+
+#[test]
+fn back_to_back_clears_are_worth_half_more() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.bonus(&clear(4, 0, true, false));
+    scoring.handle(&line_clear(4));
+    assert_eq!(scoring.score(), 1200);
+}
+
+#[test]
+fn perfect_clears_are_worth_a_bonus() {
+    let mut scoring = Scoring::new(1, 10);
+    scoring.bonus(&clear(2, 0, false, true));
+    assert_eq!(scoring.score(), 1200);
+    scoring.bonus(&clear(4, 0, true, true));
+    assert_eq!(scoring.score(), 1200 + 400 + 3200);
+}
+
+#[test]
+fn resumed_scoring_continues_from_the_saved_lines() {
+    let mut scoring = Scoring::resume(1, 10, 5000, 19);
+    assert_eq!(scoring.level(), 2);
+    scoring.handle(&line_clear(1));
+    assert_eq!(scoring.score(), 5000 + 2 * 100);
+    assert_eq!(scoring.level(), 3);
+    assert_eq!(scoring.lines(), 20);
+}
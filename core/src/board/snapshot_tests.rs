@@ -0,0 +1,71 @@
+use super::*;
+use crate::randomizer::Bag;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Red);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn bag() -> Bag {
+    Bag::seven(rand::rngs::StdRng::from_seed(SEED))
+}
+
+/// Plays a few tetrominos
+fn play(board: &mut Board<7, 7>, tetrominos: usize) {
+    for _ in 0..tetrominos {
+        while !board.try_spawn() {
+            board.cascade();
+        }
+        board.hard_drop();
+    }
+    while !board.try_spawn() {
+        board.cascade();
+    }
+}
+
+#[test]
+fn restored_board_is_the_saved_one() {
+    // given a board after a few tetrominos, with a held one
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [X, X, o, o, o, o, o,],
+    ], bag());
+    play(&mut board, 2);
+    board.hold();
+    board.left();
+
+    // when it is restored from a snapshot
+    let restored = Board::<7, 7>::restore(board.snapshot(), bag());
+
+    // then it is the same
+    assert_eq!(restored.snapshot(), board.snapshot());
+    assert_eq!(restored, board);
+}
+
+#[test]
+fn restored_board_deals_the_same_tetrominos() {
+    // given a board after a few tetrominos
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], bag());
+    play(&mut board, 3);
+
+    // when it is restored from a snapshot
+    let mut restored = Board::<7, 7>::restore(board.snapshot(), bag());
+
+    // then the next tetrominos are the same as if the game had continued
+    for _ in 0..10 {
+        board.tetromino = None;
+        restored.tetromino = None;
+        board.spawn_tetromino();
+        restored.spawn_tetromino();
+        assert_eq!(restored.tetromino, board.tetromino);
+        assert_eq!(restored.queue(), board.queue());
+    }
+}
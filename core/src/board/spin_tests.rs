@@ -0,0 +1,288 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Red);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+/// Board with a slot for a T pointing down, covered on its left side
+#[rustfmt::skip]
+const T_SLOT: [[Option<Color>; 7]; 7] = [
+    [None; 7],
+    [None; 7],
+    [None; 7],
+    [None; 7],
+    [Some(Color::Red), Some(Color::Red), None, None, None, None, None],
+    [Some(Color::Red), None, None, None, Some(Color::Red), Some(Color::Red), Some(Color::Red)],
+    [Some(Color::Red), Some(Color::Red), None, Some(Color::Red), Some(Color::Red), Some(Color::Red), Some(Color::Red)],
+];
+
+fn lock_events(events: Vec<Event>) -> Vec<Lock> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::Lock(lock) => Some(lock),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn t_rotated_into_a_slot_with_both_front_corners_is_a_full_t_spin() {
+    // given a T pointing right above the slot
+    let mut board = Board::<7, 7>::new(T_SLOT, rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 0,
+        row: 4,
+        orientation: 1,
+    });
+
+    // when it is rotated into the slot and locks
+    assert!(board.rotate());
+    board.hard_drop();
+
+    // then the lock is a T-spin that clears two rows
+    assert_eq!(
+        board.take_events(),
+        vec![
+            Event::Lock(Lock {
+                index: 2,
+                spin: Some(Spin::Full),
+            }),
+            Event::LineClear(LineClear {
+                rows: 2,
+                chain: false,
+                perfect_clear: false,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn t_with_a_single_front_corner_is_a_mini_t_spin() {
+    // given a T pointing up against the floor, with a block over its left arm, that was just
+    // rotated
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, X, o, o, o, o, o,],
+        [X, o, o, o, X, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 0,
+        row: 5,
+        orientation: 0,
+    });
+    board.last_rotation = Some(Rotation {
+        from: 3,
+        to: 0,
+        kick: 0,
+    });
+
+    // when it locks
+    board.hard_drop();
+
+    // then the lock is a mini T-spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 2,
+            spin: Some(Spin::Mini),
+        }]
+    );
+}
+
+#[test]
+fn t_moved_into_a_slot_is_not_a_t_spin() {
+    // given a T in the slot that was not rotated
+    let mut board = Board::<7, 7>::new(T_SLOT, rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 0,
+        row: 4,
+        orientation: 2,
+    });
+
+    // when it locks
+    board.hard_drop();
+
+    // then the lock is not a spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 2,
+            spin: None,
+        }]
+    );
+}
+
+#[test]
+fn t_rotated_in_the_open_is_not_a_t_spin() {
+    // given a T on the floor of an empty board
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 4,
+        orientation: 1,
+    });
+
+    // when it is rotated and locks
+    assert!(board.rotate());
+    board.hard_drop();
+
+    // then the lock is not a spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 2,
+            spin: None,
+        }]
+    );
+}
+
+/// Board with a closed 2x2 hole at the bottom left
+#[rustfmt::skip]
+const O_HOLE: [[Option<Color>; 7]; 7] = [
+    [None; 7],
+    [None; 7],
+    [None; 7],
+    [None; 7],
+    [None, Some(Color::Red), None, None, None, None, None],
+    [Some(Color::Red), None, None, Some(Color::Red), None, None, None],
+    [Some(Color::Red), None, None, Some(Color::Red), None, None, None],
+];
+
+fn o_in_the_hole(all_spins: bool) -> Board<7, 7> {
+    let mut board = Board::<7, 7>::new(O_HOLE, rand::rngs::StdRng::from_seed(SEED));
+    board.set_all_spins(all_spins);
+    board.tetromino = Some(TetrominoPosition {
+        index: 1,
+        col: 0,
+        row: 4,
+        orientation: 0,
+    });
+    board
+}
+
+#[test]
+fn immobile_tetromino_after_a_rotation_is_a_mini_spin_when_all_spins_are_detected() {
+    // given an O that cannot move in its hole
+    let mut board = o_in_the_hole(true);
+
+    // when it is rotated and locks
+    assert!(board.rotate());
+    board.hard_drop();
+
+    // then the lock is a mini spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 1,
+            spin: Some(Spin::Mini),
+        }]
+    );
+}
+
+#[test]
+fn immobile_tetromino_is_not_a_spin_when_only_t_spins_are_detected() {
+    // given an O that cannot move in its hole
+    let mut board = o_in_the_hole(false);
+
+    // when it is rotated and locks
+    assert!(board.rotate());
+    board.hard_drop();
+
+    // then the lock is not a spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 1,
+            spin: None,
+        }]
+    );
+}
+
+#[test]
+fn failed_drop_after_a_rotation_keeps_the_spin() {
+    // given an O that was rotated in its hole
+    let mut board = o_in_the_hole(true);
+    assert!(board.rotate());
+
+    // when the player tries to move it down
+    assert_eq!(board.soft_drop(), 0);
+    board.hard_drop();
+
+    // then the lock is still a spin
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 1,
+            spin: Some(Spin::Mini),
+        }]
+    );
+}
+
+/// The T of `t_with_a_single_front_corner_is_a_mini_t_spin`, rotated with SRS and a given kick
+fn t_with_a_single_front_corner_kicked(from: usize, kick: usize) -> Board<7, 7> {
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, X, o, o, o, o, o,],
+        [X, o, o, o, X, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.set_rotation_system(rotation::Srs);
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 0,
+        row: 5,
+        orientation: 0,
+    });
+    board.last_rotation = Some(Rotation {
+        from: from,
+        to: 0,
+        kick: kick,
+    });
+    board
+}
+
+#[test]
+fn last_srs_kick_of_a_quarter_turn_makes_a_full_t_spin() {
+    let mut board = t_with_a_single_front_corner_kicked(3, 4);
+    board.hard_drop();
+    assert_eq!(
+        lock_events(board.take_events()),
+        vec![Lock {
+            index: 2,
+            spin: Some(Spin::Full),
+        }]
+    );
+}
+
+#[test]
+fn other_srs_kicks_moving_two_rows_keep_mini_t_spins() {
+    // the fourth kick of a quarter turn and the kicks of a half turn can move two rows too
+    for (from, kick) in [(3, 3), (2, 4), (2, 5)] {
+        let mut board = t_with_a_single_front_corner_kicked(from, kick);
+        board.hard_drop();
+        assert_eq!(
+            lock_events(board.take_events()),
+            vec![Lock {
+                index: 2,
+                spin: Some(Spin::Mini),
+            }]
+        );
+    }
+}
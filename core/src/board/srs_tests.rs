@@ -0,0 +1,190 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+static I: Option<Color> = Some(Color::Cyan);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+#[test]
+fn srs_kicks_the_i_two_columns_away_from_the_wall() {
+    // given a vertical I against the left wall
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.set_rotation_system(rotation::Srs);
+    board.tetromino = Some(TetrominoPosition {
+        index: 0,
+        col: -2,
+        row: 1,
+        orientation: 1,
+    });
+
+    // when it is rotated
+    board.rotate();
+
+    // then it is kicked two columns to the right
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [I, I, I, I, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn simple_rotation_cannot_kick_the_i_two_columns_away_from_the_wall() {
+    // given a vertical I against the left wall
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 0,
+        col: -2,
+        row: 1,
+        orientation: 1,
+    });
+
+    // when it is rotated
+    board.rotate();
+
+    // then it does not rotate
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [I, o, o, o, o, o, o,],
+        [I, o, o, o, o, o, o,],
+        [I, o, o, o, o, o, o,],
+        [I, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn srs_kicks_a_tetromino_off_the_ground() {
+    // given a T on the ground
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.set_rotation_system(rotation::Srs);
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 5,
+        orientation: 0,
+    });
+
+    // when it is rotated through the ground
+    board.rotate();
+
+    // then it is kicked up
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, X, o, o, o,],
+        [o, o, X, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn simple_rotation_cannot_kick_a_tetromino_off_the_ground() {
+    // given a T on the ground
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 5,
+        orientation: 0,
+    });
+
+    // when it is rotated through the ground
+    board.rotate();
+
+    // then it does not rotate
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn srs_kicks_a_t_down_into_a_slot() {
+    // given a T above a slot that it can only enter with a downward kick
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [X, X, o, o, X, X, X,],
+        [X, o, o, o, X, X, X,],
+        [X, X, X, o, X, X, X,],
+        [X, X, X, X, X, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.set_rotation_system(rotation::Srs);
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 1,
+        orientation: 2,
+    });
+
+    // when it is rotated
+    board.rotate();
+
+    // then it is kicked two rows down into the slot
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [X, X, o, X, X, X, X,],
+        [X, o, X, X, X, X, X,],
+        [X, X, X, X, X, X, X,],
+        [X, X, X, X, X, X, X,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn srs_kicks_a_180_degree_rotation_off_the_ground() {
+    // given a T on the ground
+    let mut board = Board::<7, 7>::new([[o; 7]; 7], rand::rngs::StdRng::from_seed(SEED));
+    board.set_rotation_system(rotation::Srs);
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: 5,
+        orientation: 0,
+    });
+
+    // when it is rotated 180 degrees through the ground
+    board.rotate_180();
+
+    // then it is kicked up
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, o, o, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, o, X, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
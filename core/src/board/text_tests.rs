@@ -0,0 +1,118 @@
+use super::*;
+use rand::prelude::*;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn rng() -> rand::rngs::StdRng {
+    rand::rngs::StdRng::from_seed(SEED)
+}
+
+#[test]
+fn board_is_written_with_the_letters_of_its_colors() {
+    // given a board with blocks of several colors and a falling T
+    let i = Some(Color::Cyan);
+    let o = Some(Color::Blue);
+    let z = Some(Color::Red);
+    #[rustfmt::skip]
+    let mut board = Board::<5, 4>::new([
+        [None, None, None, None, None],
+        [None, None, None, None, None],
+        [i,    None, None, None, None],
+        [i,    z,    None, o,    None],
+    ], rng());
+    board.tetromino = Some(TetrominoPosition {
+        index: T,
+        col: 0,
+        row: 0,
+        orientation: 0,
+    });
+
+    // then the falling tetromino is written in lowercase
+    assert_eq!(board.to_text(), "..t..\n.ttt.\nI....\nIZ.O.");
+}
+
+#[test]
+fn written_board_is_parsed_back() {
+    let text = "
+        ..t..
+        .ttt.
+        I....
+        IZ.O.
+    ";
+    let board = Board::<5, 4>::parse(text, rng()).unwrap();
+    assert_eq!(
+        board.tetromino,
+        Some(TetrominoPosition {
+            index: T,
+            col: 0,
+            row: 0,
+            orientation: 0,
+        })
+    );
+    assert_eq!(board.to_text(), "..t..\n.ttt.\nI....\nIZ.O.");
+}
+
+#[test]
+fn orientation_of_the_falling_tetromino_is_parsed() {
+    let board = Board::<5, 4>::parse(
+        "
+        .....
+        ..t..
+        ..tt.
+        ..t..
+        ",
+        rng(),
+    )
+    .unwrap();
+    assert_eq!(
+        board.tetromino,
+        Some(TetrominoPosition {
+            index: T,
+            col: 0,
+            row: 1,
+            orientation: 1,
+        })
+    );
+}
+
+#[test]
+fn parsed_tetromino_can_be_moved() {
+    // given a parsed board with a falling T
+    let mut board = Board::<5, 4>::parse(
+        "
+        ..t..
+        .ttt.
+        .....
+        ...OO
+        ",
+        rng(),
+    )
+    .unwrap();
+
+    // when it moves and falls
+    board.left();
+    board.fall();
+
+    // then the text follows it
+    assert_eq!(board.to_text(), ".....\n.t...\nttt..\n...OO");
+}
+
+#[test]
+fn invalid_boards_are_rejected() {
+    let parse = |text: &str| Board::<3, 2>::parse(text, rng()).map(|board| board.to_text());
+    assert_eq!(parse("...\n..."), Ok("...\n...".to_string()));
+    assert_eq!(parse("..."), Err("expected 2 rows, got 1".to_string()));
+    assert_eq!(
+        parse("...\n...."),
+        Err("expected 3 columns in row 1".to_string())
+    );
+    assert_eq!(parse("...\n.Q."), Err("unknown color: Q".to_string()));
+    assert_eq!(
+        parse("tl.\n..."),
+        Err("several falling tetrominos: l".to_string())
+    );
+    assert_eq!(
+        parse("tt.\n..."),
+        Err("invalid falling tetromino: t".to_string())
+    );
+}
@@ -0,0 +1,134 @@
+use super::*;
+use rand::prelude::*;
+
+static X: Option<Color> = Some(Color::Magenta);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SEED: [u8; 32] = [30; 32];
+
+#[test]
+fn spawning_over_blocks_is_a_block_out() {
+    // given blocks stacked up to the spawn area
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+        [o, o, o, X, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+
+    // when a tetromino spawns
+    board.advance();
+
+    // then the game is over
+    assert_eq!(board.top_out(), Some(TopOut::BlockOut));
+    assert_eq!(board.take_events(), vec![Event::TopOut(TopOut::BlockOut)]);
+}
+
+#[test]
+fn board_does_not_advance_after_a_top_out() {
+    // given a board that topped out
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.advance();
+    assert_eq!(board.top_out(), Some(TopOut::BlockOut));
+
+    // when the game advances
+    board.advance();
+
+    // then the overlapping tetromino does not move
+    #[rustfmt::skip]
+    let expected = Board::<7, 7>::new([
+        [o, o, X, X, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+        [o, o, X, o, o, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn locking_above_the_field_is_a_lock_out() {
+    // given a tetromino entirely above the field, resting on blocks
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: -2,
+        orientation: 0,
+    });
+
+    // when it locks
+    board.advance();
+
+    // then the game is over
+    assert_eq!(board.top_out(), Some(TopOut::LockOut));
+    assert_eq!(
+        board.take_events(),
+        vec![
+            Event::Lock(Lock {
+                index: 2,
+                spin: None
+            }),
+            Event::TopOut(TopOut::LockOut)
+        ]
+    );
+}
+
+#[test]
+fn locking_partially_in_the_field_is_not_a_lock_out() {
+    // given a tetromino partially above the field, resting on blocks
+    #[rustfmt::skip]
+    let mut board = Board::<7, 7>::new([
+        [o, o, o, o, o, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+        [o, o, X, X, X, o, o,],
+    ], rand::rngs::StdRng::from_seed(SEED));
+    board.tetromino = Some(TetrominoPosition {
+        index: 2,
+        col: 1,
+        row: -1,
+        orientation: 0,
+    });
+
+    // when it locks
+    board.advance();
+
+    // then the game continues
+    assert_eq!(board.top_out(), None);
+    assert_eq!(
+        board.take_events(),
+        vec![Event::Lock(Lock {
+            index: 2,
+            spin: None
+        })]
+    );
+}
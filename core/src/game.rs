@@ -0,0 +1,265 @@
+use crate::auto_repeat;
+use crate::board;
+use crate::gravity;
+use crate::input::Action;
+use crate::lock_delay;
+use crate::replay::Settings;
+use crate::rotation;
+use crate::save;
+use crate::scoring;
+use crate::streaks;
+use rand::SeedableRng;
+
+/// Number of upcoming tetrominos shown next to the board
+const PREVIEW_LENGTH: usize = 5;
+
+const START_LEVEL: u32 = 1;
+
+const LINES_PER_LEVEL: u32 = 10;
+
+/// Frames a tetromino can stay on the ground before locking
+const LOCK_DELAY_FRAMES: u32 = 30;
+
+/// Number of times moving or rotating a tetromino on the ground restarts its lock delay
+const MAX_LOCK_DELAY_RESETS: u32 = 15;
+
+/// Loose blocks cascade at the same speed whatever the level
+const CASCADE_FRAMES_PER_ROW: f64 = 6.0;
+
+pub const DEFAULT_SETTINGS: Settings = Settings {
+    randomizer: crate::randomizer::Kind::SevenBag,
+    das: 10,
+    arr: 2,
+    soft_drop_factor: 20,
+};
+
+/// The rules of a game, simulated one frame at a time from the actions of the player. It does
+/// not depend on the platform: the page and the headless simulations share it
+pub struct Game {
+    seed: u64,
+    settings: Settings,
+    board: board::Board<10, 20>,
+    scoring: scoring::Scoring,
+    streaks: streaks::Streaks,
+    tetromino_gravity: gravity::Accumulator,
+    cascade_gravity: gravity::Accumulator,
+    lock_delay: lock_delay::LockDelay,
+    // true while the soft drop key is held
+    soft_drop: bool,
+    auto_repeat: auto_repeat::AutoRepeat,
+    // frames simulated since the start of the game
+    tick: u64,
+}
+
+/// What happened during a frame
+pub struct Frame {
+    pub events: Vec<board::Event>,
+    /// line clears of the frame, with the streaks they are part of
+    pub clears: Vec<streaks::Clear>,
+}
+
+impl Game {
+    pub fn new(seed: u64, settings: Settings) -> Game {
+        let board = board::Board::new(
+            [[None; 10]; 20],
+            settings
+                .randomizer
+                .create(rand::rngs::StdRng::seed_from_u64(seed)),
+        );
+        Game::with_board(
+            seed,
+            settings,
+            board,
+            scoring::Scoring::new(START_LEVEL, LINES_PER_LEVEL),
+        )
+    }
+
+    /// Continues a saved game. Streaks, lock delay and held keys are not saved and start over
+    pub fn resume(save: save::Save<10, 20>) -> Game {
+        let board = board::Board::restore(
+            save.board,
+            save.settings
+                .randomizer
+                .create(rand::rngs::StdRng::seed_from_u64(save.seed)),
+        );
+        Game::with_board(
+            save.seed,
+            save.settings,
+            board,
+            scoring::Scoring::resume(START_LEVEL, LINES_PER_LEVEL, save.score, save.lines),
+        )
+    }
+
+    fn with_board(
+        seed: u64,
+        settings: Settings,
+        mut board: board::Board<10, 20>,
+        scoring: scoring::Scoring,
+    ) -> Game {
+        board.set_preview_length(PREVIEW_LENGTH);
+        board.set_rotation_system(rotation::Srs);
+        board.set_all_spins(true);
+        Game {
+            seed: seed,
+            settings: settings,
+            board: board,
+            scoring: scoring,
+            streaks: streaks::Streaks::new(),
+            tetromino_gravity: gravity::Accumulator::new(),
+            cascade_gravity: gravity::Accumulator::new(),
+            lock_delay: lock_delay::LockDelay::new(LOCK_DELAY_FRAMES, MAX_LOCK_DELAY_RESETS),
+            soft_drop: false,
+            auto_repeat: auto_repeat::AutoRepeat::new(settings.das, settings.arr),
+            tick: 0,
+        }
+    }
+
+    pub fn save(&self) -> save::Save<10, 20> {
+        save::Save {
+            seed: self.seed,
+            settings: self.settings,
+            score: self.scoring.score(),
+            lines: self.scoring.lines(),
+            board: self.board.snapshot(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn settings(&self) -> Settings {
+        self.settings
+    }
+
+    pub fn board(&self) -> &board::Board<10, 20> {
+        &self.board
+    }
+
+    pub fn scoring(&self) -> &scoring::Scoring {
+        &self.scoring
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns why the game is over, or None while it can continue
+    pub fn top_out(&self) -> Option<board::TopOut> {
+        self.board.top_out()
+    }
+
+    /// Applies a key press. Pausing and restarting are up to the caller
+    pub fn press(&mut self, action: Action) {
+        match action {
+            Action::Left => {
+                self.moved(|board| board.left());
+                self.auto_repeat.press(auto_repeat::Direction::Left);
+            }
+            Action::Right => {
+                self.moved(|board| board.right());
+                self.auto_repeat.press(auto_repeat::Direction::Right);
+            }
+            Action::SoftDrop => self.soft_drop = true,
+            Action::HardDrop => {
+                let rows = self.board.hard_drop();
+                self.scoring.hard_drop(rows);
+            }
+            Action::RotateClockwise => self.moved(|board| board.rotate()),
+            Action::RotateCounterclockwise => self.moved(|board| board.rotate_counterclockwise()),
+            Action::Rotate180 => self.moved(|board| board.rotate_180()),
+            Action::Hold => {
+                if self.board.hold() {
+                    self.lock_delay.reset();
+                }
+            }
+            Action::Pause | Action::Restart => (),
+        }
+    }
+
+    /// Applies a key release
+    pub fn release(&mut self, action: Action) {
+        match action {
+            Action::SoftDrop => self.soft_drop = false,
+            Action::Left => self.auto_repeat.release(auto_repeat::Direction::Left),
+            Action::Right => self.auto_repeat.release(auto_repeat::Direction::Right),
+            _ => (),
+        }
+    }
+
+    /// Moves the falling tetromino, restarting the lock delay if it moved
+    fn moved(&mut self, action: impl FnOnce(&mut board::Board<10, 20>) -> bool) {
+        if action(&mut self.board) {
+            self.lock_delay.moved();
+        }
+    }
+
+    /// Simulates one frame of the game
+    pub fn step(&mut self) -> Frame {
+        self.tick += 1;
+        let mut tetromino_gravity = gravity::Gravity::for_level(self.scoring.level());
+        if self.soft_drop {
+            tetromino_gravity = tetromino_gravity.times(self.settings.soft_drop_factor);
+        }
+        if let Some((direction, moves)) = self.auto_repeat.step() {
+            for _ in 0..moves {
+                let moved = match direction {
+                    auto_repeat::Direction::Left => self.board.left(),
+                    auto_repeat::Direction::Right => self.board.right(),
+                };
+                if !moved {
+                    break;
+                }
+                self.lock_delay.moved();
+            }
+        }
+        let rows = self.tetromino_gravity.step(tetromino_gravity);
+        for _ in 0..rows {
+            if self.soft_drop {
+                let dropped = self.board.soft_drop();
+                self.scoring.soft_drop(dropped);
+                if dropped == 0 {
+                    break;
+                }
+            } else if !self.board.fall() {
+                break;
+            }
+        }
+        let grounded = self.board.is_grounded();
+        if let Some(row) = self.board.tetromino_bottom() {
+            if self.lock_delay.step(grounded, row) {
+                self.board.lock();
+            }
+        }
+        let rows = self
+            .cascade_gravity
+            .step(gravity::Gravity::from_frames_per_row(
+                CASCADE_FRAMES_PER_ROW,
+            ));
+        for _ in 0..rows {
+            self.board.cascade();
+        }
+        if self.board.try_spawn() {
+            self.tetromino_gravity.reset();
+            self.lock_delay.reset();
+        }
+
+        let events = self.board.take_events();
+        let mut clears = vec![];
+        for event in &events {
+            if let Some(clear) = self.streaks.handle(event) {
+                // the bonus uses the level before the clear, so it comes before the event
+                self.scoring.bonus(&clear);
+                clears.push(clear);
+            }
+            self.scoring.handle(event);
+        }
+        Frame {
+            events: events,
+            clears: clears,
+        }
+    }
+}
+
+#[cfg(test)]
+mod game_tests;
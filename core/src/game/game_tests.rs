@@ -0,0 +1,122 @@
+use super::*;
+
+const SEED: u64 = 1234;
+
+/// Plays the first frames of a game, hard dropping a tetromino every 10 frames
+fn play(game: &mut Game, frames: u64) -> Vec<board::Event> {
+    let mut events = vec![];
+    for _ in 0..frames {
+        if game.tick() % 10 == 9 {
+            game.press(Action::HardDrop);
+        }
+        events.extend(game.step().events);
+    }
+    events
+}
+
+#[test]
+fn first_frame_spawns_a_tetromino() {
+    let mut game = Game::new(SEED, DEFAULT_SETTINGS);
+    assert!(game
+        .board()
+        .cells()
+        .iter()
+        .flatten()
+        .all(|cell| cell.is_none()));
+    game.step();
+    assert_eq!(game.tick(), 1);
+    assert!(game
+        .board()
+        .cells()
+        .iter()
+        .flatten()
+        .any(|cell| cell.is_some()));
+}
+
+#[test]
+fn hard_drop_locks_the_tetromino_and_is_scored() {
+    // given a tetromino that just spawned
+    let mut game = Game::new(SEED, DEFAULT_SETTINGS);
+    game.step();
+
+    // when it is hard dropped
+    game.press(Action::HardDrop);
+    let frame = game.step();
+
+    // then it locks and the rows it dropped are scored
+    assert!(matches!(frame.events[..], [board::Event::Lock(_)]));
+    assert!(game.scoring().score() > 0);
+    assert_eq!(game.scoring().score() % 2, 0);
+}
+
+#[test]
+fn same_inputs_play_the_same_game() {
+    let mut game = Game::new(SEED, DEFAULT_SETTINGS);
+    let mut other_game = Game::new(SEED, DEFAULT_SETTINGS);
+    assert_eq!(play(&mut game, 200), play(&mut other_game, 200));
+    assert_eq!(game.board(), other_game.board());
+    assert_eq!(game.scoring().score(), other_game.scoring().score());
+}
+
+#[test]
+fn resumed_game_continues_where_it_was_saved() {
+    // given a game saved after a few tetrominos
+    let mut game = Game::new(SEED, DEFAULT_SETTINGS);
+    play(&mut game, 55);
+    let save = game.save();
+
+    // when it is resumed
+    let mut resumed = Game::resume(save.clone());
+
+    // then it has the same board, score and upcoming tetrominos
+    assert_eq!(resumed.save(), save);
+    game.press(Action::HardDrop);
+    resumed.press(Action::HardDrop);
+    assert_eq!(game.step().events, resumed.step().events);
+    assert_eq!(game.board().snapshot(), resumed.board().snapshot());
+}
+
+#[test]
+fn pausing_and_restarting_are_left_to_the_caller() {
+    let mut game = Game::new(SEED, DEFAULT_SETTINGS);
+    game.step();
+    let board = game.board().to_text();
+    game.press(Action::Pause);
+    game.press(Action::Restart);
+    assert_eq!(game.board().to_text(), board);
+    assert_eq!(game.tick(), 1);
+}
+
+#[test]
+fn rotating_on_the_ground_cannot_delay_the_lock_forever() {
+    // given a tetromino on the ground
+    let mut game = Game::new(0, DEFAULT_SETTINGS);
+    game.step();
+    game.press(Action::SoftDrop);
+    while !game.board().is_grounded() {
+        game.step();
+    }
+    game.release(Action::SoftDrop);
+
+    // when it keeps rotating back and forth, even with kicks lifting it
+    let mut locked = false;
+    let max_frames = 4 * (MAX_LOCK_DELAY_RESETS + LOCK_DELAY_FRAMES) as u64;
+    for frame in 0..max_frames {
+        match frame % 8 {
+            0 => game.press(Action::RotateClockwise),
+            4 => game.press(Action::RotateCounterclockwise),
+            _ => (),
+        }
+        let events = game.step().events;
+        if events
+            .iter()
+            .any(|event| matches!(event, board::Event::Lock(_)))
+        {
+            locked = true;
+            break;
+        }
+    }
+
+    // then it locks once its restarts are used up
+    assert!(locked);
+}
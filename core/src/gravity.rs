@@ -0,0 +1,69 @@
+/// Number of simulation steps per second
+pub const FRAMES_PER_SECOND: i64 = 60;
+
+/// Frames needed for the falling tetromino to move one row down, indexed by level - 1, following
+/// the guideline curve `(0.8 - (level - 1) * 0.007) ^ (level - 1)` seconds per row.
+/// Values under 1 mean the tetromino moves several rows in a single frame; the last level is 20G
+/// (20 rows per frame, the tetromino reaches the ground as soon as it spawns).
+const FRAMES_PER_ROW: [f64; 19] = [
+    60.0, 47.58, 37.07, 28.36, 21.31, 15.72, 11.38, 8.08, 5.63, 3.85, 2.58, 1.69, 1.09, 0.69, 0.42,
+    0.26, 0.15, 0.09, 0.05,
+];
+
+/// Fixed-point unit of gravity: one row per frame
+const ROW: u32 = 1 << 16;
+
+/// Distance travelled by a falling element in one frame, in 1/65536th of a row (integers keep the
+/// simulation deterministic)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gravity(u32);
+
+impl Gravity {
+    pub fn from_frames_per_row(frames_per_row: f64) -> Gravity {
+        // rounded up so that moving one row never takes more than `frames_per_row` frames
+        Gravity((ROW as f64 / frames_per_row).ceil() as u32)
+    }
+
+    /// Gravity of the falling tetromino at a given level (levels start at 1)
+    pub fn for_level(level: u32) -> Gravity {
+        let index = (level.max(1) as usize - 1).min(FRAMES_PER_ROW.len() - 1);
+        Gravity::from_frames_per_row(FRAMES_PER_ROW[index])
+    }
+
+    /// Multiplies the gravity, e.g. to make the tetromino fall faster during a soft drop
+    pub fn times(self, factor: u32) -> Gravity {
+        Gravity(self.0.saturating_mul(factor))
+    }
+}
+
+/// Highest soft drop factor: 20G (20 rows per frame) from the gravity of the first level
+pub const MAX_SOFT_DROP_FACTOR: u32 = 20 * FRAMES_PER_SECOND as u32;
+
+/// Accumulates gravity frame after frame and tells when a falling element has to move
+#[derive(Default)]
+pub struct Accumulator {
+    // u64 so that the strongest gravity cannot overflow it
+    distance: u64,
+}
+
+impl Accumulator {
+    pub fn new() -> Accumulator {
+        Accumulator::default()
+    }
+
+    /// Advances one frame and returns the number of rows to move down during this frame
+    pub fn step(&mut self, gravity: Gravity) -> u32 {
+        self.distance += gravity.0 as u64;
+        let rows = self.distance / ROW as u64;
+        self.distance %= ROW as u64;
+        rows as u32
+    }
+
+    /// Forgets the distance accumulated so far, e.g. when a new tetromino spawns
+    pub fn reset(&mut self) {
+        self.distance = 0;
+    }
+}
+
+#[cfg(test)]
+mod gravity_tests;
@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn level_1_moves_one_row_per_second() {
+    let mut accumulator = Accumulator::new();
+    let mut rows = 0;
+    for _ in 0..(FRAMES_PER_SECOND - 1) {
+        rows += accumulator.step(Gravity::for_level(1));
+    }
+    assert_eq!(rows, 0);
+    rows += accumulator.step(Gravity::for_level(1));
+    assert_eq!(rows, 1);
+}
+
+#[test]
+fn sub_row_gravity_moves_several_rows_per_frame() {
+    let mut accumulator = Accumulator::new();
+    assert_eq!(accumulator.step(Gravity::for_level(15)), 2);
+}
+
+#[test]
+fn levels_past_the_table_are_20g() {
+    let mut accumulator = Accumulator::new();
+    assert_eq!(
+        Gravity::for_level(19),
+        Gravity::from_frames_per_row(1. / 20.)
+    );
+    assert_eq!(Gravity::for_level(50), Gravity::for_level(19));
+    assert_eq!(accumulator.step(Gravity::for_level(50)), 20);
+}
+
+#[test]
+fn reset_forgets_accumulated_distance() {
+    let mut accumulator = Accumulator::new();
+    for _ in 0..(FRAMES_PER_SECOND - 1) {
+        accumulator.step(Gravity::for_level(1));
+    }
+    accumulator.reset();
+    assert_eq!(accumulator.step(Gravity::for_level(1)), 0);
+}
+
+#[test]
+fn strongest_gravity_does_not_overflow() {
+    let mut accumulator = Accumulator::new();
+    let gravity = Gravity::for_level(50).times(u32::MAX);
+    assert_eq!(accumulator.step(gravity), u32::MAX / ROW);
+    // the fraction of a row left by the first frame is kept
+    assert_eq!(accumulator.step(gravity), u32::MAX / ROW + 1);
+}
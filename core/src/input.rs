@@ -0,0 +1,70 @@
+/// Something the player can do with the keyboard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Left,
+    Right,
+    SoftDrop,
+    HardDrop,
+    RotateClockwise,
+    RotateCounterclockwise,
+    Rotate180,
+    Hold,
+    Pause,
+    Restart,
+}
+
+impl Action {
+    /// Parses the name of an action, as written in key bindings
+    pub fn from_name(name: &str) -> Option<Action> {
+        match name {
+            "left" => Some(Action::Left),
+            "right" => Some(Action::Right),
+            "soft-drop" => Some(Action::SoftDrop),
+            "hard-drop" => Some(Action::HardDrop),
+            "rotate-cw" => Some(Action::RotateClockwise),
+            "rotate-ccw" => Some(Action::RotateCounterclockwise),
+            "rotate-180" => Some(Action::Rotate180),
+            "hold" => Some(Action::Hold),
+            "pause" => Some(Action::Pause),
+            "restart" => Some(Action::Restart),
+            _ => None,
+        }
+    }
+
+    /// Returns the name parsed by `from_name`
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::SoftDrop => "soft-drop",
+            Action::HardDrop => "hard-drop",
+            Action::RotateClockwise => "rotate-cw",
+            Action::RotateCounterclockwise => "rotate-ccw",
+            Action::Rotate180 => "rotate-180",
+            Action::Hold => "hold",
+            Action::Pause => "pause",
+            Action::Restart => "restart",
+        }
+    }
+}
+
+/// Parses key bindings written as comma-separated `action:key` pairs, e.g.
+/// `rotate-cw:x,rotate-ccw:z,hold:c`. An action may be bound to several keys, and the key is
+/// everything after the first colon so that `:` itself can be bound
+pub fn parse_bindings(text: &str) -> Result<Vec<(String, Action)>, String> {
+    text.split(',')
+        .map(|binding| {
+            let (name, key) = binding
+                .split_once(':')
+                .ok_or(format!("missing key in binding: {}", binding))?;
+            let action = Action::from_name(name).ok_or(format!("unknown action: {}", name))?;
+            if key.is_empty() {
+                return Err(format!("missing key in binding: {}", binding));
+            }
+            Ok((key.to_string(), action))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod input_tests;
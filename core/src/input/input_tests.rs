@@ -0,0 +1,41 @@
+use super::*;
+
+const ACTIONS: [Action; 10] = [
+    Action::Left,
+    Action::Right,
+    Action::SoftDrop,
+    Action::HardDrop,
+    Action::RotateClockwise,
+    Action::RotateCounterclockwise,
+    Action::Rotate180,
+    Action::Hold,
+    Action::Pause,
+    Action::Restart,
+];
+
+#[test]
+fn names_are_parsed_back() {
+    for action in ACTIONS {
+        assert_eq!(Action::from_name(action.name()), Some(action));
+    }
+}
+
+#[test]
+fn bindings_are_parsed() {
+    assert_eq!(
+        parse_bindings("rotate-cw:x,rotate-cw:ArrowUp,hold::"),
+        Ok(vec![
+            ("x".to_string(), Action::RotateClockwise),
+            ("ArrowUp".to_string(), Action::RotateClockwise),
+            (":".to_string(), Action::Hold),
+        ])
+    );
+}
+
+#[test]
+fn invalid_bindings_are_rejected() {
+    assert!(parse_bindings("jump:w").is_err());
+    assert!(parse_bindings("hold").is_err());
+    assert!(parse_bindings("hold:").is_err());
+    assert!(parse_bindings("").is_err());
+}
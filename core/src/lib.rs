@@ -0,0 +1,17 @@
+//! Rules of the game, independent of the platform: the board and its physics, the randomizers,
+//! scoring, replays and saves. The web frontend draws and plays it in the browser
+
+pub mod auto_repeat;
+pub mod board;
+pub mod game;
+pub mod gravity;
+pub mod input;
+pub mod lock_delay;
+pub mod randomizer;
+pub mod replay;
+pub mod rotation;
+pub mod save;
+pub mod scoring;
+pub mod simulation;
+pub mod streaks;
+pub mod tetrominos;
@@ -0,0 +1,58 @@
+/// Time a tetromino can stay on the ground before it locks. Moving or rotating it successfully
+/// restarts the timer, a limited number of times per tetromino so that it cannot be delayed
+/// forever. Only falling lower than it ever was gives the tetromino its restarts back
+pub struct LockDelay {
+    delay: u32,
+    max_resets: u32,
+    elapsed: u32,
+    resets: u32,
+    // lowest row reached by the tetromino
+    lowest_row: Option<isize>,
+}
+
+impl LockDelay {
+    /// `delay` is in frames
+    pub fn new(delay: u32, max_resets: u32) -> LockDelay {
+        LockDelay {
+            delay: delay,
+            max_resets: max_resets,
+            elapsed: 0,
+            resets: 0,
+            lowest_row: None,
+        }
+    }
+
+    /// Advances one frame and returns true when the tetromino has to lock. `row` is the lowest
+    /// row covered by the tetromino. The timer only runs while the tetromino is on the ground,
+    /// leaving the ground pauses it: kicks lifting the tetromino do not restart it
+    pub fn step(&mut self, grounded: bool, row: isize) -> bool {
+        if self.lowest_row.is_none_or(|lowest_row| row > lowest_row) {
+            self.lowest_row = Some(row);
+            self.elapsed = 0;
+            self.resets = 0;
+        }
+        if grounded {
+            self.elapsed += 1;
+        }
+        self.elapsed >= self.delay
+    }
+
+    /// Restarts the timer after the tetromino moved or rotated, if it has not been restarted too
+    /// many times already
+    pub fn moved(&mut self) {
+        if self.resets < self.max_resets {
+            self.resets += 1;
+            self.elapsed = 0;
+        }
+    }
+
+    /// Starts over for a new tetromino
+    pub fn reset(&mut self) {
+        self.elapsed = 0;
+        self.resets = 0;
+        self.lowest_row = None;
+    }
+}
+
+#[cfg(test)]
+mod lock_delay_tests;
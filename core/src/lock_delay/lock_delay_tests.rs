@@ -0,0 +1,83 @@
+use super::*;
+
+#[test]
+fn tetromino_locks_after_the_delay_on_the_ground() {
+    let mut lock_delay = LockDelay::new(3, 15);
+    assert!(!lock_delay.step(true, 5));
+    assert!(!lock_delay.step(true, 5));
+    assert!(lock_delay.step(true, 5));
+}
+
+#[test]
+fn tetromino_does_not_lock_while_falling() {
+    let mut lock_delay = LockDelay::new(3, 15);
+    for _ in 0..10 {
+        assert!(!lock_delay.step(false, 5));
+    }
+}
+
+#[test]
+fn leaving_the_ground_pauses_the_timer() {
+    let mut lock_delay = LockDelay::new(3, 15);
+    lock_delay.step(true, 5);
+    lock_delay.step(true, 5);
+    assert!(!lock_delay.step(false, 4));
+    assert!(lock_delay.step(true, 5));
+}
+
+#[test]
+fn falling_lower_restarts_the_timer() {
+    let mut lock_delay = LockDelay::new(3, 15);
+    lock_delay.step(true, 5);
+    lock_delay.step(true, 5);
+    lock_delay.step(false, 6);
+    assert!(!lock_delay.step(true, 7));
+    assert!(!lock_delay.step(true, 7));
+    assert!(lock_delay.step(true, 7));
+}
+
+#[test]
+fn falling_lower_gives_the_restarts_back() {
+    let mut lock_delay = LockDelay::new(3, 1);
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    lock_delay.step(true, 6);
+    lock_delay.step(true, 6);
+    lock_delay.moved();
+    assert!(!lock_delay.step(true, 6));
+}
+
+#[test]
+fn moving_restarts_the_timer() {
+    let mut lock_delay = LockDelay::new(3, 15);
+    lock_delay.step(true, 5);
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    assert!(!lock_delay.step(true, 5));
+    assert!(!lock_delay.step(true, 5));
+    assert!(lock_delay.step(true, 5));
+}
+
+#[test]
+fn moving_restarts_the_timer_a_limited_number_of_times() {
+    let mut lock_delay = LockDelay::new(3, 2);
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    assert!(!lock_delay.step(true, 5));
+    assert!(lock_delay.step(true, 5));
+}
+
+#[test]
+fn reset_allows_new_restarts() {
+    let mut lock_delay = LockDelay::new(3, 1);
+    lock_delay.moved();
+    lock_delay.reset();
+    lock_delay.step(true, 5);
+    lock_delay.step(true, 5);
+    lock_delay.moved();
+    assert!(!lock_delay.step(true, 5));
+}
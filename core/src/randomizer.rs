@@ -0,0 +1,159 @@
+use rand::prelude::*;
+
+/// Number of distinct tetrominos a randomizer picks from
+pub const TETROMINO_COUNT: usize = 7;
+
+// indexes of the tetrominos in `Board::TETROMINOS`
+const O: usize = 1;
+const S: usize = 5;
+const Z: usize = 6;
+
+/// Chooses which tetromino spawns next
+pub trait Randomizer {
+    /// Returns the index in `Board::TETROMINOS` of the next tetromino
+    fn next(&mut self) -> usize;
+}
+
+impl<R: Randomizer + ?Sized> Randomizer for Box<R> {
+    fn next(&mut self) -> usize {
+        (**self).next()
+    }
+}
+
+/// Randomizers that can be chosen for a game
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Uniform,
+    SevenBag,
+    FourteenBag,
+    History,
+}
+
+impl Kind {
+    /// Parses the name of a randomizer: uniform, 7-bag, 14-bag or history
+    pub fn from_name(name: &str) -> Option<Kind> {
+        match name {
+            "uniform" => Some(Kind::Uniform),
+            "7-bag" => Some(Kind::SevenBag),
+            "14-bag" => Some(Kind::FourteenBag),
+            "history" => Some(Kind::History),
+            _ => None,
+        }
+    }
+
+    /// Returns the name parsed by `from_name`
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Uniform => "uniform",
+            Kind::SevenBag => "7-bag",
+            Kind::FourteenBag => "14-bag",
+            Kind::History => "history",
+        }
+    }
+
+    pub fn create(self, rng: StdRng) -> Box<dyn Randomizer> {
+        match self {
+            Kind::Uniform => Box::new(rng),
+            Kind::SevenBag => Box::new(Bag::seven(rng)),
+            Kind::FourteenBag => Box::new(Bag::fourteen(rng)),
+            Kind::History => Box::new(History::new(rng, 4)),
+        }
+    }
+}
+
+/// Uniform randomizer: every tetromino has the same chance of being picked each time, which can
+/// lead to long droughts and floods
+impl Randomizer for StdRng {
+    fn next(&mut self) -> usize {
+        self.next_u32() as usize % TETROMINO_COUNT
+    }
+}
+
+/// Bag randomizer: the tetrominos are dealt from a shuffled bag containing each of them a given
+/// number of times, and the bag is refilled once empty. One copy is the guideline 7-bag
+pub struct Bag {
+    rng: StdRng,
+    copies: usize,
+    bag: Vec<usize>,
+}
+
+impl Bag {
+    pub fn new(rng: StdRng, copies: usize) -> Bag {
+        Bag {
+            rng: rng,
+            copies: copies,
+            bag: vec![],
+        }
+    }
+
+    pub fn seven(rng: StdRng) -> Bag {
+        Bag::new(rng, 1)
+    }
+
+    pub fn fourteen(rng: StdRng) -> Bag {
+        Bag::new(rng, 2)
+    }
+}
+
+impl Randomizer for Bag {
+    fn next(&mut self) -> usize {
+        if self.bag.is_empty() {
+            for _ in 0..self.copies {
+                self.bag.extend(0..TETROMINO_COUNT);
+            }
+            self.bag.shuffle(&mut self.rng);
+        }
+        self.bag.pop().unwrap()
+    }
+}
+
+/// History randomizer from Tetris The Grand Master: a tetromino is rerolled a few times while it
+/// is one of the last 4 dealt, and the first tetromino is never an S, a Z or an O
+pub struct History {
+    rng: StdRng,
+    rerolls: usize,
+    history: [usize; 4],
+    first: bool,
+}
+
+impl History {
+    /// `rerolls` is 4 in the first game and 6 in its sequels
+    pub fn new(rng: StdRng, rerolls: usize) -> History {
+        History {
+            rng: rng,
+            rerolls: rerolls,
+            // the history starts filled with S and Z so that they are unlikely at the beginning
+            history: [Z, S, S, Z],
+            first: true,
+        }
+    }
+}
+
+impl Randomizer for History {
+    fn next(&mut self) -> usize {
+        let index = if self.first {
+            self.first = false;
+            loop {
+                let index = self.rng.next_u32() as usize % TETROMINO_COUNT;
+                if index != S && index != Z && index != O {
+                    break index;
+                }
+            }
+        } else {
+            let mut index = self.rng.next_u32() as usize % TETROMINO_COUNT;
+            for _ in 0..self.rerolls {
+                if !self.history.contains(&index) {
+                    break;
+                }
+                index = self.rng.next_u32() as usize % TETROMINO_COUNT;
+            }
+            index
+        };
+        self.history.rotate_right(1);
+        self.history[0] = index;
+        index
+    }
+}
+
+#[cfg(test)]
+mod randomizer_tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+const SEED: [u8; 32] = [30; 32];
+
+fn sequence(randomizer: &mut dyn Randomizer, length: usize) -> Vec<usize> {
+    (0..length).map(|_| randomizer.next()).collect()
+}
+
+fn count(sequence: &[usize], index: usize) -> usize {
+    sequence.iter().filter(|i| **i == index).count()
+}
+
+#[test]
+fn seven_bag_deals_every_tetromino_once_per_bag() {
+    let mut randomizer = Bag::seven(StdRng::from_seed(SEED));
+    for _ in 0..10 {
+        let bag = sequence(&mut randomizer, 7);
+        for index in 0..TETROMINO_COUNT {
+            assert_eq!(count(&bag, index), 1);
+        }
+    }
+}
+
+#[test]
+fn fourteen_bag_deals_every_tetromino_twice_per_bag() {
+    let mut randomizer = Bag::fourteen(StdRng::from_seed(SEED));
+    for _ in 0..10 {
+        let bag = sequence(&mut randomizer, 14);
+        for index in 0..TETROMINO_COUNT {
+            assert_eq!(count(&bag, index), 2);
+        }
+    }
+}
+
+#[test]
+fn history_never_starts_with_s_z_or_o() {
+    for seed in 0..100 {
+        let mut randomizer = History::new(StdRng::seed_from_u64(seed), 4);
+        let first = randomizer.next();
+        assert!(first != S && first != Z && first != O);
+    }
+}
+
+#[test]
+fn history_rarely_repeats_recent_tetrominos() {
+    let mut randomizer = History::new(StdRng::from_seed(SEED), 6);
+    let sequence = sequence(&mut randomizer, 1000);
+    let repeats = sequence.windows(2).filter(|w| w[0] == w[1]).count();
+    // a uniform randomizer would repeat about 1 time in 7
+    assert!(repeats < 1000 / 50);
+}
+
+#[test]
+fn uniform_picks_every_tetromino() {
+    let mut randomizer = StdRng::from_seed(SEED);
+    let sequence = sequence(&mut randomizer, 1000);
+    for index in 0..TETROMINO_COUNT {
+        assert!(count(&sequence, index) > 0);
+    }
+}
+
+#[test]
+fn randomizers_with_the_same_seed_deal_the_same_sequence() {
+    assert_eq!(
+        sequence(&mut StdRng::from_seed(SEED), 50),
+        sequence(&mut StdRng::from_seed(SEED), 50)
+    );
+    assert_eq!(
+        sequence(&mut Bag::seven(StdRng::from_seed(SEED)), 50),
+        sequence(&mut Bag::seven(StdRng::from_seed(SEED)), 50)
+    );
+    assert_eq!(
+        sequence(&mut History::new(StdRng::from_seed(SEED), 4), 50),
+        sequence(&mut History::new(StdRng::from_seed(SEED), 4), 50)
+    );
+}
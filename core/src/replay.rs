@@ -0,0 +1,192 @@
+use crate::gravity;
+use crate::input::Action;
+use crate::randomizer;
+
+/// Version of the serialized format, to reject replays recorded by an incompatible version
+const VERSION: u32 = 1;
+
+/// Options of a game that change how the inputs play out
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    pub randomizer: randomizer::Kind,
+    /// Delayed Auto Shift: frames a direction key is held before the tetromino moves again
+    pub das: u32,
+    /// Auto Repeat Rate: frames between two repeated moves, 0 to move as far as possible at once
+    pub arr: u32,
+    /// gravity multiplier while the soft drop key is held
+    pub soft_drop_factor: u32,
+}
+
+impl Settings {
+    /// Reads the randomizer, das, arr and soft drop factor parts of a serialized replay or save.
+    /// The soft drop factor must be between 1 and `gravity::MAX_SOFT_DROP_FACTOR`
+    pub fn parse(parts: &[&str]) -> Result<Settings, String> {
+        let [randomizer, das, arr, soft_drop_factor] = parts else {
+            return Err(format!("expected 4 settings, got {}", parts.len()));
+        };
+        let number = |part: &str, name: &str| {
+            part.parse::<u32>()
+                .map_err(|_| format!("invalid {}: {}", name, part))
+        };
+        let soft_drop_factor = number(soft_drop_factor, "soft drop factor")?;
+        if !(1..=gravity::MAX_SOFT_DROP_FACTOR).contains(&soft_drop_factor) {
+            return Err(format!(
+                "soft drop factor out of range: {}",
+                soft_drop_factor
+            ));
+        }
+        Ok(Settings {
+            randomizer: randomizer::Kind::from_name(randomizer)
+                .ok_or(format!("unknown randomizer: {}", randomizer))?,
+            das: number(das, "das")?,
+            arr: number(arr, "arr")?,
+            soft_drop_factor: soft_drop_factor,
+        })
+    }
+}
+
+/// A key pressed or released during a game
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Input {
+    /// frame before which the input is applied, counted from the start of the game
+    pub tick: u64,
+    pub action: Action,
+    pub pressed: bool,
+}
+
+/// Everything needed to play a game again exactly as it was played
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub seed: u64,
+    pub settings: Settings,
+    /// inputs in the order they were applied
+    pub inputs: Vec<Input>,
+}
+
+/// Letter of each action in the serialized inputs, pausing and restarting are not part of a game
+const ACTION_CODES: [(Action, char); 8] = [
+    (Action::Left, 'l'),
+    (Action::Right, 'r'),
+    (Action::SoftDrop, 's'),
+    (Action::HardDrop, 'h'),
+    (Action::RotateClockwise, 'c'),
+    (Action::RotateCounterclockwise, 'w'),
+    (Action::Rotate180, 'f'),
+    (Action::Hold, 'g'),
+];
+
+impl Replay {
+    pub fn new(seed: u64, settings: Settings) -> Replay {
+        Replay {
+            seed: seed,
+            settings: settings,
+            inputs: vec![],
+        }
+    }
+
+    /// Records an input applied before simulating a frame, unless it does not change the game
+    pub fn record(&mut self, tick: u64, action: Action, pressed: bool) {
+        if Replay::code(action).is_some() {
+            self.inputs.push(Input {
+                tick: tick,
+                action: action,
+                pressed: pressed,
+            });
+        }
+    }
+
+    /// Writes the replay as `version.seed.randomizer.das.arr.sdf.inputs`, where each input is the
+    /// number of frames since the previous one followed by the letter of its action, lowercase
+    /// when pressed and uppercase when released. The result can be used in a URL
+    pub fn serialize(&self) -> String {
+        let mut inputs = String::new();
+        let mut tick = 0;
+        for input in &self.inputs {
+            let code = Replay::code(input.action).unwrap();
+            inputs += &(input.tick - tick).to_string();
+            inputs.push(match input.pressed {
+                true => code,
+                false => code.to_ascii_uppercase(),
+            });
+            tick = input.tick;
+        }
+        format!(
+            "{}.{}.{}.{}.{}.{}.{}",
+            VERSION,
+            self.seed,
+            self.settings.randomizer.name(),
+            self.settings.das,
+            self.settings.arr,
+            self.settings.soft_drop_factor,
+            inputs
+        )
+    }
+
+    /// Reads a replay written by `serialize`
+    pub fn parse(text: &str) -> Result<Replay, String> {
+        let parts: Vec<&str> = text.split('.').collect();
+        if parts.len() != 7 {
+            return Err(format!("expected 7 parts, got {}", parts.len()));
+        }
+        let number = |part: &str, name: &str| {
+            part.parse::<u64>()
+                .map_err(|_| format!("invalid {}: {}", name, part))
+        };
+        let version = number(parts[0], "version")?;
+        if version != VERSION as u64 {
+            return Err(format!("unsupported version: {}", version));
+        }
+        let settings = Settings::parse(&parts[2..6])?;
+        let mut replay = Replay::new(number(parts[1], "seed")?, settings);
+        replay.inputs = Replay::parse_inputs(parts[6])?;
+        Ok(replay)
+    }
+
+    /// Reads inputs written as in the last part of a serialized replay, e.g. `0l12L0h18w`
+    pub fn parse_inputs(text: &str) -> Result<Vec<Input>, String> {
+        let mut inputs = vec![];
+        let mut tick: u64 = 0;
+        let mut delta = String::new();
+        for character in text.chars() {
+            if character.is_ascii_digit() {
+                delta.push(character);
+                continue;
+            }
+            let action = Replay::action(character.to_ascii_lowercase())
+                .ok_or(format!("unknown action: {}", character))?;
+            let frames = delta
+                .parse::<u64>()
+                .map_err(|_| format!("invalid frame count: {}", delta))?;
+            tick = tick
+                .checked_add(frames)
+                .ok_or(format!("frame count out of range: {}", delta))?;
+            delta.clear();
+            inputs.push(Input {
+                tick: tick,
+                action: action,
+                pressed: character.is_ascii_lowercase(),
+            });
+        }
+        if !delta.is_empty() {
+            return Err(format!("frame count without action: {}", delta));
+        }
+        Ok(inputs)
+    }
+
+    fn code(action: Action) -> Option<char> {
+        ACTION_CODES
+            .iter()
+            .find(|(coded_action, _)| *coded_action == action)
+            .map(|(_, code)| *code)
+    }
+
+    fn action(code: char) -> Option<Action> {
+        ACTION_CODES
+            .iter()
+            .find(|(_, action_code)| *action_code == code)
+            .map(|(action, _)| *action)
+    }
+}
+
+#[cfg(test)]
+mod replay_tests;
@@ -0,0 +1,91 @@
+use super::*;
+
+const SETTINGS: Settings = Settings {
+    randomizer: randomizer::Kind::SevenBag,
+    das: 10,
+    arr: 2,
+    soft_drop_factor: 20,
+};
+
+fn replay() -> Replay {
+    let mut replay = Replay::new(1234, SETTINGS);
+    replay.record(0, Action::Left, true);
+    replay.record(12, Action::Left, false);
+    replay.record(12, Action::HardDrop, true);
+    replay.record(30, Action::RotateCounterclockwise, true);
+    replay
+}
+
+#[test]
+fn inputs_not_changing_the_game_are_not_recorded() {
+    let mut replay = Replay::new(1234, SETTINGS);
+    replay.record(3, Action::Pause, true);
+    replay.record(4, Action::Restart, true);
+    assert_eq!(replay.inputs, vec![]);
+}
+
+#[test]
+fn replay_is_serialized_compactly() {
+    assert_eq!(replay().serialize(), "1.1234.7-bag.10.2.20.0l12L0h18w");
+}
+
+#[test]
+fn serialized_replay_is_parsed_back() {
+    assert_eq!(Replay::parse(&replay().serialize()), Ok(replay()));
+}
+
+#[test]
+fn replay_without_inputs_is_parsed() {
+    assert_eq!(
+        Replay::parse("1.42.history.0.0.1."),
+        Ok(Replay::new(
+            42,
+            Settings {
+                randomizer: randomizer::Kind::History,
+                das: 0,
+                arr: 0,
+                soft_drop_factor: 1,
+            }
+        ))
+    );
+}
+
+#[test]
+fn replay_of_another_version_is_rejected() {
+    assert!(Replay::parse("2.1234.7-bag.10.2.20.0l").is_err());
+}
+
+#[test]
+fn malformed_replays_are_rejected() {
+    assert!(Replay::parse("").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.20").is_err());
+    assert!(Replay::parse("1.1234.9-bag.10.2.20.0l").is_err());
+    assert!(Replay::parse("1.-1.7-bag.10.2.20.0l").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.20.0x").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.20.l").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.20.0l3").is_err());
+}
+
+#[test]
+fn frame_counts_out_of_range_are_rejected() {
+    assert_eq!(
+        Replay::parse("1.0.7-bag.10.2.20.18446744073709551615l1l"),
+        Err("frame count out of range: 1".to_string())
+    );
+    assert!(Replay::parse("1.0.7-bag.10.2.20.18446744073709551616l").is_err());
+}
+
+#[test]
+fn settings_out_of_range_are_rejected() {
+    assert_eq!(
+        Replay::parse("1.1234.7-bag.4294967296.2.20.0l"),
+        Err("invalid das: 4294967296".to_string())
+    );
+    assert_eq!(
+        Replay::parse("1.1234.7-bag.10.2.0.0l"),
+        Err("soft drop factor out of range: 0".to_string())
+    );
+    assert!(Replay::parse("1.1234.7-bag.10.2.4294967296.0l").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.1201.0l").is_err());
+    assert!(Replay::parse("1.1234.7-bag.10.2.1200.0l").is_ok());
+}
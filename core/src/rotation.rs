@@ -0,0 +1,109 @@
+// index of the I tetromino in `Board::TETROMINOS`
+const I: usize = 0;
+
+/// Decides where a tetromino can go when it is rotated into blocks or walls
+pub trait RotationSystem {
+    /// Returns the offsets (columns to the right, rows down) to try in order when a tetromino
+    /// rotates from an orientation to another; the first one that does not collide is used
+    fn kicks(&self, index: usize, from: usize, to: usize) -> Vec<(isize, isize)>;
+
+    /// Returns false if a rotation must be refused when the rotated tetromino goes through the
+    /// ground, instead of being kicked
+    fn kicks_off_the_ground(&self) -> bool {
+        true
+    }
+
+    /// Returns true if a T rotated with a kick, given by its index in `kicks`, makes a full
+    /// T-spin even when it does not point into the blocks
+    fn is_t_spin_kick(&self, _from: usize, _to: usize, _kick: usize) -> bool {
+        false
+    }
+}
+
+/// Original rotation of the game: a rotated tetromino is moved one column to the right or to the
+/// left when it collides, and cannot be rotated through the ground
+pub struct Simple;
+
+impl RotationSystem for Simple {
+    fn kicks(&self, _index: usize, _from: usize, _to: usize) -> Vec<(isize, isize)> {
+        vec![(0, 0), (1, 0), (-1, 0)]
+    }
+
+    fn kicks_off_the_ground(&self) -> bool {
+        false
+    }
+}
+
+/// Super Rotation System of the guideline games
+pub struct Srs;
+
+// kick tables of the guideline, in its own convention: (x to the right, y up), indexed by
+// `transition`
+#[rustfmt::skip]
+const SRS_KICKS: [[(isize, isize); 5]; 8] = [
+    [(0, 0), (-1, 0), (-1,  1), (0, -2), (-1, -2)], // 0 -> R
+    [(0, 0), ( 1, 0), ( 1, -1), (0,  2), ( 1,  2)], // R -> 0
+    [(0, 0), ( 1, 0), ( 1, -1), (0,  2), ( 1,  2)], // R -> 2
+    [(0, 0), (-1, 0), (-1,  1), (0, -2), (-1, -2)], // 2 -> R
+    [(0, 0), ( 1, 0), ( 1,  1), (0, -2), ( 1, -2)], // 2 -> L
+    [(0, 0), (-1, 0), (-1, -1), (0,  2), (-1,  2)], // L -> 2
+    [(0, 0), (-1, 0), (-1, -1), (0,  2), (-1,  2)], // L -> 0
+    [(0, 0), ( 1, 0), ( 1,  1), (0, -2), ( 1, -2)], // 0 -> L
+];
+
+#[rustfmt::skip]
+const SRS_I_KICKS: [[(isize, isize); 5]; 8] = [
+    [(0, 0), (-2, 0), ( 1, 0), (-2, -1), ( 1,  2)], // 0 -> R
+    [(0, 0), ( 2, 0), (-1, 0), ( 2,  1), (-1, -2)], // R -> 0
+    [(0, 0), (-1, 0), ( 2, 0), (-1,  2), ( 2, -1)], // R -> 2
+    [(0, 0), ( 1, 0), (-2, 0), ( 1, -2), (-2,  1)], // 2 -> R
+    [(0, 0), ( 2, 0), (-1, 0), ( 2,  1), (-1, -2)], // 2 -> L
+    [(0, 0), (-2, 0), ( 1, 0), (-2, -1), ( 1,  2)], // L -> 2
+    [(0, 0), ( 1, 0), (-2, 0), ( 1, -2), (-2,  1)], // L -> 0
+    [(0, 0), (-1, 0), ( 2, 0), (-1,  2), ( 2, -1)], // 0 -> L
+];
+
+// the guideline has no 180 degree rotations, these kicks are the ones of the SRS+ extension,
+// indexed by the starting orientation
+#[rustfmt::skip]
+const SRS_180_KICKS: [[(isize, isize); 6]; 4] = [
+    [(0, 0), ( 0,  1), ( 1,  1), (-1,  1), ( 1, 0), (-1, 0)], // 0 -> 2
+    [(0, 0), ( 1,  0), ( 1,  2), ( 1,  1), ( 0, 2), ( 0, 1)], // R -> L
+    [(0, 0), ( 0, -1), (-1, -1), ( 1, -1), (-1, 0), ( 1, 0)], // 2 -> 0
+    [(0, 0), (-1,  0), (-1,  2), (-1,  1), ( 0, 2), ( 0, 1)], // L -> R
+];
+
+impl Srs {
+    /// Returns the line of the quarter turn kick tables for a rotation between two orientations
+    fn transition(from: usize, to: usize) -> usize {
+        match (from, to) {
+            (0, 1) => 0,
+            (1, 0) => 1,
+            (1, 2) => 2,
+            (2, 1) => 3,
+            (2, 3) => 4,
+            (3, 2) => 5,
+            (3, 0) => 6,
+            _ => 7,
+        }
+    }
+}
+
+impl RotationSystem for Srs {
+    fn kicks(&self, index: usize, from: usize, to: usize) -> Vec<(isize, isize)> {
+        let kicks: &[(isize, isize)] = if (from + 2) % 4 == to {
+            &SRS_180_KICKS[from]
+        } else if index == I {
+            &SRS_I_KICKS[Srs::transition(from, to)]
+        } else {
+            &SRS_KICKS[Srs::transition(from, to)]
+        };
+        // rows go down on the board
+        kicks.iter().map(|(x, y)| (*x, -*y)).collect()
+    }
+
+    /// The last kick of the quarter turns, (±1, ∓2), only fits into a slot surrounded by blocks
+    fn is_t_spin_kick(&self, from: usize, to: usize, kick: usize) -> bool {
+        (from + 2) % 4 != to && kick == 4
+    }
+}
@@ -0,0 +1,196 @@
+use crate::board::{Color, Snapshot, TetrominoPosition};
+use crate::replay::Settings;
+
+/// Version of the serialized format, saves written by another version are not resumed
+const VERSION: u32 = 1;
+
+/// A game in progress, saved to be resumed later
+#[derive(Clone, Debug, PartialEq)]
+pub struct Save<const WIDTH: usize, const HEIGHT: usize> {
+    pub seed: u64,
+    pub settings: Settings,
+    pub score: u64,
+    pub lines: u32,
+    pub board: Snapshot<WIDTH, HEIGHT>,
+}
+
+/// Most tetrominos a saved game can have drawn (a tetromino per second for more than 10 days),
+/// restoring the randomizer draws them all again
+const MAX_DRAWS: u64 = 1_000_000;
+
+/// Highest score of a saved game, far enough from the limit of `u64` that scoring can go on
+/// without overflowing
+const MAX_SCORE: u64 = 1_000_000_000_000_000;
+
+/// Most rows a saved game can have cleared, each drawn tetromino completes at most 4 rows
+const MAX_LINES: u32 = 4 * MAX_DRAWS as u32;
+
+/// Character of empty cells and missing values
+const EMPTY: char = '-';
+
+impl<const WIDTH: usize, const HEIGHT: usize> Save<WIDTH, HEIGHT> {
+    /// Writes the save as `version.seed.randomizer.das.arr.sdf.score.lines.draws.held.can_hold.
+    /// queue.tetromino.cells`, where the tetromino is `index,col,row,orientation` and the cells
+    /// are rows from top to bottom separated by `/`, with the letter of the color of each block
+    pub fn serialize(&self) -> String {
+        let board = &self.board;
+        let held = match board.held {
+            Some(index) => index.to_string(),
+            None => EMPTY.to_string(),
+        };
+        let queue: String = board.queue.iter().map(|index| index.to_string()).collect();
+        let tetromino = match &board.tetromino {
+            Some(tetromino) => format!(
+                "{},{},{},{}",
+                tetromino.index, tetromino.col, tetromino.row, tetromino.orientation
+            ),
+            None => EMPTY.to_string(),
+        };
+        let cells: Vec<String> = board
+            .cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|block| block.map_or(EMPTY, |color| color.letter()))
+                    .collect()
+            })
+            .collect();
+        format!(
+            "{}.{}.{}.{}.{}.{}.{}.{}.{}.{}.{}.{}.{}.{}",
+            VERSION,
+            self.seed,
+            self.settings.randomizer.name(),
+            self.settings.das,
+            self.settings.arr,
+            self.settings.soft_drop_factor,
+            self.score,
+            self.lines,
+            board.draws,
+            held,
+            board.can_hold as u8,
+            queue,
+            tetromino,
+            cells.join("/")
+        )
+    }
+
+    /// Reads a save written by `serialize`, for a board of the same size
+    pub fn parse(text: &str) -> Result<Save<WIDTH, HEIGHT>, String> {
+        let parts: Vec<&str> = text.split('.').collect();
+        let number = |part: &str, name: &str| {
+            part.parse::<u64>()
+                .map_err(|_| format!("invalid {}: {}", name, part))
+        };
+        // the version comes first so that saves with another layout are rejected as such
+        let version = number(parts[0], "version")?;
+        if version != VERSION as u64 {
+            return Err(format!("unsupported version: {}", version));
+        }
+        if parts.len() != 14 {
+            return Err(format!("expected 14 parts, got {}", parts.len()));
+        }
+        let settings = Settings::parse(&parts[2..6])?;
+        let tetromino_index = |index: u64| match index {
+            0..7 => Ok(index as usize),
+            _ => Err(format!("invalid tetromino: {}", index)),
+        };
+        let held = match parts[9] {
+            "-" => None,
+            held => Some(tetromino_index(number(held, "held tetromino")?)?),
+        };
+        let can_hold = match parts[10] {
+            "0" => false,
+            "1" => true,
+            can_hold => return Err(format!("invalid can hold: {}", can_hold)),
+        };
+        let queue = parts[11]
+            .chars()
+            .map(|index| tetromino_index(number(&index.to_string(), "queue")?))
+            .collect::<Result<Vec<usize>, String>>()?;
+        let tetromino = match parts[12] {
+            "-" => None,
+            tetromino => Some(Save::<WIDTH, HEIGHT>::parse_tetromino(tetromino)?),
+        };
+        // every tetromino of the queue was drawn
+        let draws = number(parts[8], "draws")?;
+        if draws < queue.len() as u64 || draws > MAX_DRAWS {
+            return Err(format!("draws out of range: {}", draws));
+        }
+        let rows: Vec<&str> = parts[13].split('/').collect();
+        if rows.len() != HEIGHT {
+            return Err(format!("expected {} rows, got {}", HEIGHT, rows.len()));
+        }
+        let mut cells = [[None; WIDTH]; HEIGHT];
+        for (row, text) in rows.iter().enumerate() {
+            if text.chars().count() != WIDTH {
+                return Err(format!("expected {} columns in row {}", WIDTH, row));
+            }
+            for (col, letter) in text.chars().enumerate() {
+                cells[row][col] = match letter {
+                    EMPTY => None,
+                    letter => Some(
+                        Color::from_letter(letter).ok_or(format!("unknown color: {}", letter))?,
+                    ),
+                };
+            }
+        }
+        let board = Snapshot {
+            cells: cells,
+            tetromino: tetromino,
+            held: held,
+            can_hold: can_hold,
+            queue: queue,
+            draws: draws,
+        };
+        if !board.tetromino_fits() {
+            return Err(format!("tetromino not fitting the board: {}", parts[12]));
+        }
+        let score = number(parts[6], "score")?;
+        if score > MAX_SCORE {
+            return Err(format!("score out of range: {}", score));
+        }
+        let lines = parts[7]
+            .parse::<u32>()
+            .map_err(|_| format!("invalid lines: {}", parts[7]))?;
+        if lines > MAX_LINES {
+            return Err(format!("lines out of range: {}", lines));
+        }
+        Ok(Save {
+            seed: number(parts[1], "seed")?,
+            settings: settings,
+            score: score,
+            lines: lines,
+            board: board,
+        })
+    }
+
+    fn parse_tetromino(text: &str) -> Result<TetrominoPosition, String> {
+        let values = text
+            .split(',')
+            .map(|value| {
+                value
+                    .parse::<isize>()
+                    .map_err(|_| format!("invalid tetromino: {}", text))
+            })
+            .collect::<Result<Vec<isize>, String>>()?;
+        match values[..] {
+            // the 4x4 shapes of the tetrominos can stick out of the board by up to 3 cells
+            [_, col, row, _]
+                if !(-4..WIDTH as isize).contains(&col)
+                    || !(-4..HEIGHT as isize).contains(&row) =>
+            {
+                Err(format!("tetromino out of the board: {}", text))
+            }
+            [index @ 0..7, col, row, orientation @ 0..4] => Ok(TetrominoPosition {
+                index: index as usize,
+                col: col,
+                row: row,
+                orientation: orientation as usize,
+            }),
+            _ => Err(format!("invalid tetromino: {}", text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod save_tests;
@@ -0,0 +1,150 @@
+use super::*;
+use crate::randomizer;
+
+static X: Option<Color> = Some(Color::Red);
+static T: Option<Color> = Some(Color::Magenta);
+#[allow(non_upper_case_globals)]
+static o: Option<Color> = None;
+
+const SETTINGS: Settings = Settings {
+    randomizer: randomizer::Kind::SevenBag,
+    das: 10,
+    arr: 2,
+    soft_drop_factor: 20,
+};
+
+fn save() -> Save<4, 3> {
+    Save {
+        seed: 1234,
+        settings: SETTINGS,
+        score: 4200,
+        lines: 12,
+        board: Snapshot {
+            #[rustfmt::skip]
+            cells: [
+                [o, o, o, o,],
+                [o, T, o, o,],
+                [X, X, o, X,],
+            ],
+            tetromino: Some(TetrominoPosition {
+                index: 3,
+                col: -1,
+                row: -1,
+                orientation: 2,
+            }),
+            held: Some(6),
+            can_hold: false,
+            queue: vec![0, 4, 2],
+            draws: 9,
+        },
+    }
+}
+
+#[test]
+fn save_is_serialized_compactly() {
+    assert_eq!(
+        save().serialize(),
+        "1.1234.7-bag.10.2.20.4200.12.9.6.0.042.3,-1,-1,2.----/-T--/ZZ-Z"
+    );
+}
+
+#[test]
+fn serialized_save_is_parsed_back() {
+    assert_eq!(Save::parse(&save().serialize()), Ok(save()));
+}
+
+#[test]
+fn save_without_tetrominos_is_parsed_back() {
+    let mut save = save();
+    save.board.tetromino = None;
+    save.board.held = None;
+    assert_eq!(Save::parse(&save.serialize()), Ok(save));
+}
+
+#[test]
+fn saves_of_other_versions_are_rejected() {
+    assert_eq!(
+        Save::<4, 3>::parse("0.1234.7-bag"),
+        Err("unsupported version: 0".to_string())
+    );
+}
+
+#[test]
+fn saves_of_boards_of_another_size_are_rejected() {
+    let text = save().serialize();
+    assert_eq!(
+        Save::<4, 4>::parse(&text),
+        Err("expected 4 rows, got 3".to_string())
+    );
+    assert_eq!(
+        Save::<5, 3>::parse(&text),
+        Err("expected 5 columns in row 0".to_string())
+    );
+}
+
+#[test]
+fn invalid_saves_are_rejected() {
+    assert!(Save::<4, 3>::parse("").is_err());
+    assert!(Save::<4, 3>::parse("1.1234").is_err());
+    let text = save().serialize();
+    assert!(Save::<4, 3>::parse(&text.replace("042", "048")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace("-T--", "-Q--")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,-1,-1")).is_err());
+}
+
+#[test]
+fn settings_and_lines_out_of_range_are_rejected() {
+    let text = save().serialize();
+    assert!(Save::<4, 3>::parse(&text.replace(".10.2.20.", ".4294967306.2.20.")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace(".10.2.20.", ".10.2.0.")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace(".4200.12.", ".4200.4294967308.")).is_err());
+}
+
+#[test]
+fn saves_with_too_many_or_too_few_draws_are_rejected() {
+    let text = save().serialize();
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace(".12.9.", ".12.1000000000000000.")),
+        Err("draws out of range: 1000000000000000".to_string())
+    );
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace(".12.9.", ".12.2.")),
+        Err("draws out of range: 2".to_string())
+    );
+}
+
+#[test]
+fn tetrominos_out_of_the_board_are_rejected() {
+    let text = save().serialize();
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "2,3,-4000000000000000000,0")),
+        Err("tetromino out of the board: 2,3,-4000000000000000000,0".to_string())
+    );
+    assert!(Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,4,-1,2")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,-1,3,2")).is_err());
+    // inside the range but sticking out of the walls or the ground
+    assert!(Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,-2,-1,2")).is_err());
+    assert!(Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,-1,2,2")).is_err());
+}
+
+#[test]
+fn tetrominos_overlapping_blocks_are_rejected() {
+    let text = save().serialize();
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace("3,-1,-1,2", "3,-1,0,2")),
+        Err("tetromino not fitting the board: 3,-1,0,2".to_string())
+    );
+}
+
+#[test]
+fn scores_and_lines_out_of_range_are_rejected() {
+    let text = save().serialize();
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace(".4200.12.", ".18446744073709551615.12.")),
+        Err("score out of range: 18446744073709551615".to_string())
+    );
+    assert_eq!(
+        Save::<4, 3>::parse(&text.replace(".4200.12.", ".4200.4294967295.")),
+        Err("lines out of range: 4294967295".to_string())
+    );
+}
@@ -0,0 +1,135 @@
+use crate::board;
+use crate::streaks;
+
+/// Keeps track of the score, level and cleared lines of a game, following the guideline values
+pub struct Scoring {
+    score: u64,
+    level: u32,
+    lines: u32,
+    start_level: u32,
+    lines_per_level: u32,
+    // spin of the last locked tetromino, until the rows it completes are cleared
+    spin: Option<board::Spin>,
+}
+
+impl Scoring {
+    pub fn new(start_level: u32, lines_per_level: u32) -> Scoring {
+        Scoring {
+            score: 0,
+            level: start_level,
+            lines: 0,
+            start_level: start_level,
+            lines_per_level: lines_per_level,
+            spin: None,
+        }
+    }
+
+    /// Continues the scoring of a saved game, from its score and cleared lines
+    pub fn resume(start_level: u32, lines_per_level: u32, score: u64, lines: u32) -> Scoring {
+        Scoring {
+            score: score,
+            level: start_level + lines / lines_per_level,
+            lines: lines,
+            ..Scoring::new(start_level, lines_per_level)
+        }
+    }
+
+    pub fn score(&self) -> u64 {
+        self.score
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn lines(&self) -> u32 {
+        self.lines
+    }
+
+    /// Updates the score with something that happened on the board
+    pub fn handle(&mut self, event: &board::Event) {
+        match event {
+            board::Event::Lock(lock) => self.lock(lock.spin),
+            board::Event::LineClear(line_clear) => {
+                // rows completed by a cascade are not part of the spin
+                let spin = match line_clear.chain {
+                    false => self.spin.take(),
+                    true => None,
+                };
+                self.line_clear(line_clear.rows, spin)
+            }
+            board::Event::TopOut(_) => (),
+        }
+    }
+
+    /// Rewards the streaks a clear is part of. Must be called before handling the line clear
+    /// event itself, so that the bonus uses the level before the clear
+    pub fn bonus(&mut self, clear: &streaks::Clear) {
+        let mut points = 50 * clear.combo as u64;
+        if clear.back_to_back {
+            // back-to-back clears are worth one and a half times their usual points
+            points += Scoring::line_clear_points(clear.rows, clear.spin()) / 2;
+        }
+        if clear.perfect_clear {
+            points += match clear.rows {
+                0 => 0,
+                1 => 800,
+                2 => 1200,
+                3 => 1800,
+                _ if clear.back_to_back => 3200,
+                _ => 2000,
+            };
+        }
+        self.score += points * self.level as u64;
+    }
+
+    /// Rewards rows moved down by the player (1 point per row)
+    pub fn soft_drop(&mut self, rows: usize) {
+        self.score += rows as u64;
+    }
+
+    /// Rewards rows dropped instantly by the player (2 points per row)
+    pub fn hard_drop(&mut self, rows: usize) {
+        self.score += 2 * rows as u64;
+    }
+
+    fn lock(&mut self, spin: Option<board::Spin>) {
+        // spins are worth points even without clearing rows, the rest is added if the lock
+        // clears rows
+        self.score += Scoring::line_clear_points(0, spin) * self.level as u64;
+        self.spin = spin;
+    }
+
+    fn line_clear(&mut self, rows: usize, spin: Option<board::Spin>) {
+        // points are computed with the level before the clear, as in the guideline
+        let points = Scoring::line_clear_points(rows, spin) - Scoring::line_clear_points(0, spin);
+        self.score += points * self.level as u64;
+        self.lines += rows as u32;
+        self.level = self.start_level + self.lines / self.lines_per_level;
+    }
+
+    fn line_clear_points(rows: usize, spin: Option<board::Spin>) -> u64 {
+        match spin {
+            // 400, 800, 1200, 1600 for a T-spin, single, double, triple
+            Some(board::Spin::Full) => return 400 * (rows as u64 + 1),
+            Some(board::Spin::Mini) => {
+                return match rows {
+                    0 => 100,
+                    1 => 200,
+                    _ => 400,
+                };
+            }
+            None => (),
+        }
+        match rows {
+            0 => 0,
+            1 => 100,
+            2 => 300,
+            3 => 500,
+            4 => 800,
+            // cascades can clear more than 4 rows at once: each extra row is worth a quarter of a
+            // tetris
+            _ => 800 + (rows as u64 - 4) * 200,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Headless games played from a script of inputs, for the `headless` binary
+
+use crate::board;
+use crate::game;
+use crate::gravity;
+use crate::randomizer;
+use crate::replay;
+use std::ops::Range;
+
+/// One hour of play
+const DEFAULT_MAX_FRAMES: u64 = 60 * 60 * 60;
+
+/// What to simulate, from the command line and the script
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    /// seed of the first game, the next ones use the following seeds
+    pub seed: u64,
+    pub games: u64,
+    /// frames after which a game is stopped
+    pub max_frames: u64,
+    pub settings: replay::Settings,
+    /// play the script again from the start when it ends
+    pub repeat: bool,
+    /// print the boards with ANSI colors
+    pub ansi: bool,
+    /// path of the script
+    pub script: String,
+    pub inputs: Vec<replay::Input>,
+}
+
+/// How a game ended
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub frames: u64,
+    pub score: u64,
+    pub level: u32,
+    pub lines: u32,
+    pub tetrominos: u64,
+    /// None when the game reached the frame limit
+    pub top_out: Option<board::TopOut>,
+}
+
+impl Options {
+    /// Reads the command line arguments, without the name of the program. The inputs are read
+    /// from the script afterwards, by `read_script`
+    pub fn parse(arguments: Vec<String>) -> Result<Options, String> {
+        let mut options = Options {
+            seed: 0,
+            games: 1,
+            max_frames: DEFAULT_MAX_FRAMES,
+            settings: game::DEFAULT_SETTINGS,
+            repeat: false,
+            ansi: false,
+            script: String::new(),
+            inputs: vec![],
+        };
+        let mut script = None;
+        let mut arguments = arguments.into_iter();
+        while let Some(argument) = arguments.next() {
+            let mut value =
+                |name: &str| arguments.next().ok_or(format!("missing value of {}", name));
+            let number = |name: &str, value: String| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid {}: {}", name, value))
+            };
+            let setting = |name: &str, value: String| {
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid {}: {}", name, value))
+            };
+            match argument.as_str() {
+                "--seed" => options.seed = number("seed", value("--seed")?)?,
+                "--games" => options.games = number("number of games", value("--games")?)?,
+                "--frames" => options.max_frames = number("frame limit", value("--frames")?)?,
+                "--randomizer" => {
+                    let name = value("--randomizer")?;
+                    options.settings.randomizer = randomizer::Kind::from_name(&name)
+                        .ok_or(format!("unknown randomizer: {}", name))?;
+                }
+                "--das" => options.settings.das = setting("das", value("--das")?)?,
+                "--arr" => options.settings.arr = setting("arr", value("--arr")?)?,
+                "--sdf" => {
+                    options.settings.soft_drop_factor =
+                        setting("sdf", value("--sdf")?)?.clamp(1, gravity::MAX_SOFT_DROP_FACTOR)
+                }
+                "--loop" => options.repeat = true,
+                "--ansi" => options.ansi = true,
+                _ if argument.starts_with("--") => {
+                    return Err(format!("unknown option: {}", argument))
+                }
+                _ if script.is_none() => script = Some(argument),
+                _ => return Err(format!("unexpected argument: {}", argument)),
+            }
+        }
+        options.script = script.ok_or("missing script")?;
+        Ok(options)
+    }
+
+    /// Reads the inputs of the script, whitespace is ignored. A whole replay also gives the seed
+    /// and settings of the games
+    pub fn read_script(&mut self, text: &str) -> Result<(), String> {
+        let text: String = text.split_whitespace().collect();
+        // only replays have dots
+        if text.contains('.') {
+            let replay = replay::Replay::parse(&text)?;
+            self.seed = replay.seed;
+            self.settings = replay.settings;
+            self.inputs = replay.inputs;
+        } else {
+            self.inputs = replay::Replay::parse_inputs(&text)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the seeds of the games
+    pub fn seeds(&self) -> Result<Range<u64>, String> {
+        match self.seed.checked_add(self.games) {
+            Some(end) => Ok(self.seed..end),
+            None => Err(format!(
+                "too many games from seed {}: {}",
+                self.seed, self.games
+            )),
+        }
+    }
+}
+
+/// Plays a game until it is over or reaches the frame limit
+pub fn play(game: &mut game::Game, options: &Options) -> Stats {
+    let mut tetrominos = 0;
+    let mut next = 0;
+    // frame at which the script was last started
+    let mut start: u64 = 0;
+    // a looped script starts again the frame after its last input. Scripts ending close to the
+    // largest frame count saturate rather than overflow, their inputs are never reached anyway
+    let period = options
+        .inputs
+        .last()
+        .map_or(0, |input| input.tick.saturating_add(1));
+    while game.top_out().is_none() && game.tick() < options.max_frames {
+        if next == options.inputs.len() && options.repeat && period > 0 {
+            next = 0;
+            start = start.saturating_add(period);
+        }
+        while let Some(input) = options.inputs.get(next) {
+            if start.saturating_add(input.tick) > game.tick() {
+                break;
+            }
+            match input.pressed {
+                true => game.press(input.action),
+                false => game.release(input.action),
+            }
+            next += 1;
+        }
+        for event in game.step().events {
+            if let board::Event::Lock(_) = event {
+                tetrominos += 1;
+            }
+        }
+    }
+    Stats {
+        frames: game.tick(),
+        score: game.scoring().score(),
+        level: game.scoring().level(),
+        lines: game.scoring().lines(),
+        tetrominos: tetrominos,
+        top_out: game.top_out(),
+    }
+}
+
+#[cfg(test)]
+mod simulation_tests;
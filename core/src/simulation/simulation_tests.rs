@@ -0,0 +1,153 @@
+use super::*;
+use crate::input::Action;
+
+fn arguments(arguments: &str) -> Vec<String> {
+    arguments
+        .split(' ')
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+fn options(script: &str) -> Options {
+    let mut options = Options::parse(arguments("script.txt")).unwrap();
+    options.read_script(script).unwrap();
+    options
+}
+
+#[test]
+fn options_have_defaults() {
+    let options = Options::parse(arguments("script.txt")).unwrap();
+    assert_eq!(options.seed, 0);
+    assert_eq!(options.games, 1);
+    assert_eq!(options.settings, game::DEFAULT_SETTINGS);
+    assert!(!options.repeat);
+    assert_eq!(options.script, "script.txt");
+}
+
+#[test]
+fn options_are_parsed() {
+    let options = Options::parse(arguments(
+        "--seed 42 --games 3 --frames 100 --randomizer history --das 8 --arr 0 --sdf 5000 --loop \
+         --ansi script.txt",
+    ))
+    .unwrap();
+    assert_eq!(options.seed, 42);
+    assert_eq!(options.games, 3);
+    assert_eq!(options.max_frames, 100);
+    assert_eq!(
+        options.settings,
+        replay::Settings {
+            randomizer: randomizer::Kind::History,
+            das: 8,
+            arr: 0,
+            soft_drop_factor: gravity::MAX_SOFT_DROP_FACTOR,
+        }
+    );
+    assert!(options.repeat);
+    assert!(options.ansi);
+}
+
+#[test]
+fn invalid_options_are_rejected() {
+    let parse = |text: &str| Options::parse(arguments(text));
+    assert_eq!(parse("--seed"), Err("missing value of --seed".to_string()));
+    assert_eq!(
+        parse("--das -1 script.txt"),
+        Err("invalid das: -1".to_string())
+    );
+    assert_eq!(
+        parse("--speed 1 script.txt"),
+        Err("unknown option: --speed".to_string())
+    );
+    assert_eq!(
+        parse("script.txt other.txt"),
+        Err("unexpected argument: other.txt".to_string())
+    );
+    assert_eq!(parse("--loop"), Err("missing script".to_string()));
+}
+
+#[test]
+fn games_cannot_go_past_the_last_seed() {
+    let mut options = Options::parse(arguments("--games 2 script.txt")).unwrap();
+    assert_eq!(options.seeds(), Ok(0..2));
+    options.seed = u64::MAX;
+    assert!(options.seeds().is_err());
+}
+
+#[test]
+fn script_of_inputs_is_read_ignoring_whitespace() {
+    let options = options("0h\n 9H ");
+    assert_eq!(options.seed, 0);
+    assert_eq!(
+        options.inputs,
+        vec![
+            replay::Input {
+                tick: 0,
+                action: Action::HardDrop,
+                pressed: true,
+            },
+            replay::Input {
+                tick: 9,
+                action: Action::HardDrop,
+                pressed: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn replay_script_gives_the_seed_and_settings() {
+    let options = options("1.1234.history.0.0.1.0h");
+    assert_eq!(options.seed, 1234);
+    assert_eq!(options.settings.randomizer, randomizer::Kind::History);
+    assert_eq!(options.inputs.len(), 1);
+}
+
+#[test]
+fn invalid_scripts_are_rejected() {
+    let mut options = Options::parse(arguments("script.txt")).unwrap();
+    assert!(options.read_script("0x").is_err());
+    assert!(options.read_script("2.1234.history.0.0.1.0h").is_err());
+}
+
+#[test]
+fn game_stops_at_the_frame_limit() {
+    let mut options = options("");
+    options.max_frames = 100;
+    let stats = play(&mut game::Game::new(0, options.settings), &options);
+    assert_eq!(stats.frames, 100);
+    assert_eq!(stats.tetrominos, 0);
+    assert_eq!(stats.top_out, None);
+}
+
+#[test]
+fn script_is_played_once_without_loop() {
+    // a single hard drop, once the first tetromino spawned
+    let mut options = options("1h1H");
+    options.max_frames = 500;
+    let stats = play(&mut game::Game::new(0, options.settings), &options);
+    assert_eq!(stats.tetrominos, 1);
+}
+
+#[test]
+fn looped_script_is_played_until_the_game_is_over() {
+    let mut options = options("1h8H");
+    options.repeat = true;
+    let stats = play(&mut game::Game::new(0, options.settings), &options);
+    assert!(stats.top_out.is_some());
+    // hard dropping in the middle tops out after a few tetrominos
+    assert!(stats.tetrominos > 5);
+    assert!(stats.frames < 500);
+}
+
+#[test]
+fn scripts_ending_at_the_last_frame_do_not_overflow() {
+    // a hard drop, then a release at the largest frame count
+    let mut options = options("1h18446744073709551614H");
+    options.max_frames = 500;
+    for repeat in [false, true] {
+        options.repeat = repeat;
+        let stats = play(&mut game::Game::new(0, options.settings), &options);
+        assert_eq!(stats.tetrominos, 1);
+    }
+}
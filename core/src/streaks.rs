@@ -0,0 +1,100 @@
+use crate::board;
+
+/// Follows the clears of a game to tell when they chain into combos, back-to-backs or perfect
+/// clears
+#[derive(Default)]
+pub struct Streaks {
+    // locks in a row that cleared rows, minus one, None when the last lock cleared nothing
+    combo: Option<u32>,
+    // true when the last lock clearing rows made a difficult clear (tetris or spin)
+    difficult: bool,
+    // last lock, until the next one
+    lock: Option<board::Lock>,
+    // true when the last lock cleared rows
+    lock_cleared: bool,
+}
+
+/// Rows cleared at once, or a spin that did not clear any, with the streaks they are part of
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Clear {
+    pub rows: usize,
+    /// the lock that completed the rows, None when loose blocks completed them (cascade)
+    pub lock: Option<board::Lock>,
+    /// number of locks in a row that cleared rows just before this one
+    pub combo: u32,
+    /// true when this clear and the previous one are both difficult (tetris or spin)
+    pub back_to_back: bool,
+    /// true when the board is empty after the clear
+    pub perfect_clear: bool,
+}
+
+impl Clear {
+    pub fn spin(&self) -> Option<board::Spin> {
+        self.lock.and_then(|lock| lock.spin)
+    }
+
+    fn is_difficult(&self) -> bool {
+        self.rows >= 4 || self.spin().is_some()
+    }
+}
+
+impl Streaks {
+    pub fn new() -> Streaks {
+        Streaks::default()
+    }
+
+    /// Updates the streaks with something that happened on the board. Returns the clear it made,
+    /// if any
+    pub fn handle(&mut self, event: &board::Event) -> Option<Clear> {
+        match event {
+            board::Event::Lock(lock) => self.lock(*lock),
+            board::Event::LineClear(line_clear) => self.line_clear(line_clear),
+            board::Event::TopOut(_) => None,
+        }
+    }
+
+    fn lock(&mut self, lock: board::Lock) -> Option<Clear> {
+        if !self.lock_cleared {
+            self.combo = None;
+        }
+        self.lock = Some(lock);
+        self.lock_cleared = false;
+        // spins are worth something even when they do not clear rows
+        lock.spin.map(|_| Clear {
+            rows: 0,
+            lock: Some(lock),
+            combo: 0,
+            back_to_back: false,
+            perfect_clear: false,
+        })
+    }
+
+    fn line_clear(&mut self, line_clear: &board::LineClear) -> Option<Clear> {
+        if line_clear.chain {
+            // cascades do not break streaks, nor count in them
+            return Some(Clear {
+                rows: line_clear.rows,
+                lock: None,
+                combo: 0,
+                back_to_back: false,
+                perfect_clear: line_clear.perfect_clear,
+            });
+        }
+        let combo = self.combo.map_or(0, |combo| combo + 1);
+        self.combo = Some(combo);
+        self.lock_cleared = true;
+        let mut clear = Clear {
+            rows: line_clear.rows,
+            lock: self.lock,
+            combo: combo,
+            back_to_back: false,
+            perfect_clear: line_clear.perfect_clear,
+        };
+        clear.back_to_back = self.difficult && clear.is_difficult();
+        self.difficult = clear.is_difficult();
+        Some(clear)
+    }
+}
+
+#[cfg(test)]
+mod streaks_tests;
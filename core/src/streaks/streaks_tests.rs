@@ -0,0 +1,100 @@
+use super::*;
+
+fn lock(spin: Option<board::Spin>) -> board::Event {
+    board::Event::Lock(board::Lock {
+        index: 2,
+        spin: spin,
+    })
+}
+
+fn line_clear(rows: usize) -> board::Event {
+    board::Event::LineClear(board::LineClear {
+        rows: rows,
+        chain: false,
+        perfect_clear: false,
+    })
+}
+
+#[test]
+fn locks_without_clears_are_not_reported() {
+    let mut streaks = Streaks::new();
+    assert_eq!(streaks.handle(&lock(None)), None);
+}
+
+#[test]
+fn spins_without_clears_are_reported() {
+    let mut streaks = Streaks::new();
+    let clear = streaks.handle(&lock(Some(board::Spin::Full))).unwrap();
+    assert_eq!(clear.rows, 0);
+    assert_eq!(clear.spin(), Some(board::Spin::Full));
+}
+
+#[test]
+fn consecutive_clearing_locks_make_a_combo() {
+    let mut streaks = Streaks::new();
+    streaks.handle(&lock(None));
+    assert_eq!(streaks.handle(&line_clear(1)).unwrap().combo, 0);
+    streaks.handle(&lock(None));
+    assert_eq!(streaks.handle(&line_clear(2)).unwrap().combo, 1);
+    streaks.handle(&lock(None));
+    assert_eq!(streaks.handle(&line_clear(1)).unwrap().combo, 2);
+}
+
+#[test]
+fn lock_without_clear_breaks_the_combo() {
+    let mut streaks = Streaks::new();
+    streaks.handle(&lock(None));
+    streaks.handle(&line_clear(1));
+    streaks.handle(&lock(None));
+    streaks.handle(&line_clear(1));
+    streaks.handle(&lock(None));
+    streaks.handle(&lock(None));
+    assert_eq!(streaks.handle(&line_clear(1)).unwrap().combo, 0);
+}
+
+#[test]
+fn difficult_clears_in_a_row_are_back_to_back() {
+    let mut streaks = Streaks::new();
+    streaks.handle(&lock(None));
+    assert!(!streaks.handle(&line_clear(4)).unwrap().back_to_back);
+    // locks without clears in between do not break back-to-backs
+    streaks.handle(&lock(None));
+    streaks.handle(&lock(Some(board::Spin::Full)));
+    assert!(streaks.handle(&line_clear(2)).unwrap().back_to_back);
+    streaks.handle(&lock(None));
+    assert!(streaks.handle(&line_clear(4)).unwrap().back_to_back);
+}
+
+#[test]
+fn easy_clear_breaks_back_to_backs() {
+    let mut streaks = Streaks::new();
+    streaks.handle(&lock(None));
+    streaks.handle(&line_clear(4));
+    streaks.handle(&lock(None));
+    assert!(!streaks.handle(&line_clear(3)).unwrap().back_to_back);
+    streaks.handle(&lock(None));
+    assert!(!streaks.handle(&line_clear(4)).unwrap().back_to_back);
+}
+
+#[test]
+fn chain_clears_are_reported_without_breaking_streaks() {
+    let mut streaks = Streaks::new();
+    streaks.handle(&lock(None));
+    streaks.handle(&line_clear(4));
+
+    // a cascade empties the board
+    let clear = streaks
+        .handle(&board::Event::LineClear(board::LineClear {
+            rows: 1,
+            chain: true,
+            perfect_clear: true,
+        }))
+        .unwrap();
+    assert_eq!(clear.lock, None);
+    assert!(clear.perfect_clear);
+
+    streaks.handle(&lock(None));
+    let clear = streaks.handle(&line_clear(4)).unwrap();
+    assert_eq!(clear.combo, 1);
+    assert!(clear.back_to_back);
+}
@@ -1,5 +1,7 @@
-use crate::board;
 use crate::webgl;
+use bad_tetris_core::board;
+use std::collections::HashMap;
+use std::hash_map;
 use web_sys::WebGl2RenderingContext;
 use web_sys::WebGlProgram;
 use web_sys::WebGlUniformLocation;
@@ -13,11 +15,15 @@ pub struct GridDimensions {
     pub rows: usize,
 }
 
+/// Opacity of the inside of the ghost's blocks, their outline is opaque
+const GHOST_ALPHA: f32 = 0.25;
+
 pub struct Display {
     gl: WebGl2RenderingContext,
     grid_cols_program: GridColsProgram,
     grid_rows_program: GridRowsProgram,
     blocks_program: BlocksProgram,
+    colors: HashMap<board::Color, [f32; 4]>,
 }
 
 struct BlocksProgram {
@@ -30,11 +36,28 @@ impl Display {
         let grid_cols_program = GridColsProgram::new(&gl);
         let grid_rows_program = GridRowsProgram::new(&gl);
         let blocks_program = BlocksProgram::new(&gl);
+        let colors = hash_map! {
+            // https://coolors.co/54f8d7-5474f8-d754f8-f8d756-ff9966-abf854-f86a54
+            board::Color::Cyan => [84./255., 248./255., 215./255., 1.],
+            board::Color::Blue => [84./255., 116./255., 248./255., 1.],
+            board::Color::Magenta => [215./255., 84./255., 248./255., 1.],
+            board::Color::Yellow => [248./255., 215./255., 86./255., 1.],
+            board::Color::Orange => [255./255., 153./255., 102./255., 1.],
+            board::Color::Green => [171./255., 248./255., 84./255., 1.],
+            board::Color::Red => [248./255., 106./255., 84./255., 1.],
+        };
+        // needed by translucent blocks (ghost)
+        gl.enable(WebGl2RenderingContext::BLEND);
+        gl.blend_func(
+            WebGl2RenderingContext::SRC_ALPHA,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
         Display {
             gl: gl,
             grid_cols_program: grid_cols_program,
             grid_rows_program: grid_rows_program,
             blocks_program: blocks_program,
+            colors: colors,
         }
     }
 
@@ -49,25 +72,31 @@ impl Display {
     ) where
         [(); WIDTH * HEIGHT]:,
     {
-        let grid_dimensions = GridDimensions {
-            x: -0.45,
-            y: -0.9,
-            width: 0.9,
-            height: 1.8,
-            cols: WIDTH,
-            rows: HEIGHT,
-        };
-
-        let colors = hash_map! {
-            // https://coolors.co/54f8d7-5474f8-d754f8-f8d756-ff9966-abf854-f86a54
-            board::Color::Cyan => [84./255., 248./255., 215./255., 1.],
-            board::Color::Blue => [84./255., 116./255., 248./255., 1.],
-            board::Color::Magenta => [215./255., 84./255., 248./255., 1.],
-            board::Color::Yellow => [248./255., 215./255., 86./255., 1.],
-            board::Color::Orange => [255./255., 153./255., 102./255., 1.],
-            board::Color::Green => [171./255., 248./255., 84./255., 1.],
-            board::Color::Red => [248./255., 106./255., 84./255., 1.],
-        };
+        let grid_dimensions = Display::board_grid_dimensions::<WIDTH, HEIGHT>();
+
+        // the ghost is drawn first so that the falling tetromino covers it when they overlap
+        let ghost = board.ghost();
+        for (row, ghost_row) in ghost.iter().enumerate() {
+            for (col, cell) in ghost_row.iter().enumerate() {
+                if let Some(color) = cell {
+                    let [r, g, b, _] = self.colors[color];
+                    self.blocks_program.draw(
+                        &self.gl,
+                        col,
+                        row,
+                        [r, g, b, GHOST_ALPHA],
+                        &grid_dimensions,
+                    );
+                    self.blocks_program.draw_outline(
+                        &self.gl,
+                        col,
+                        row,
+                        self.colors[color],
+                        &grid_dimensions,
+                    );
+                }
+            }
+        }
 
         let cells = board.cells();
         for row in 0..HEIGHT {
@@ -78,16 +107,127 @@ impl Display {
                         &self.gl,
                         col,
                         row,
-                        colors[&cell.unwrap()],
+                        self.colors[&cell.unwrap()],
                         &grid_dimensions,
                     );
                 }
             }
         }
 
+        self.draw_board_grid::<WIDTH, HEIGHT>();
+    }
+
+    /// Draws the grid of the board without its blocks, e.g. to hide them while the game is paused
+    pub fn draw_board_grid<const WIDTH: usize, const HEIGHT: usize>(&self) {
+        let grid_dimensions = Display::board_grid_dimensions::<WIDTH, HEIGHT>();
         self.grid_cols_program.draw(&self.gl, &grid_dimensions);
         self.grid_rows_program.draw(&self.gl, &grid_dimensions);
     }
+
+    fn board_grid_dimensions<const WIDTH: usize, const HEIGHT: usize>() -> GridDimensions {
+        GridDimensions {
+            x: -0.45,
+            y: -0.9,
+            width: 0.9,
+            height: 1.8,
+            cols: WIDTH,
+            rows: HEIGHT,
+        }
+    }
+
+    /// Draws the held tetromino in a panel on the left of the board
+    pub fn draw_held<const WIDTH: usize, const HEIGHT: usize>(
+        &self,
+        board: &board::Board<WIDTH, HEIGHT>,
+    ) {
+        let grid_dimensions = GridDimensions {
+            x: -0.9,
+            y: 0.54,
+            width: 0.36,
+            height: 0.36,
+            cols: 4,
+            rows: 4,
+        };
+
+        if let Some(index) = board.held() {
+            self.draw_preview(
+                board::Board::<WIDTH, HEIGHT>::preview(index),
+                &grid_dimensions,
+            );
+        }
+
+        self.draw_frame(&grid_dimensions);
+    }
+
+    /// Draws the upcoming tetrominos in a panel on the right of the board, first to spawn at the
+    /// top
+    pub fn draw_queue<const WIDTH: usize, const HEIGHT: usize>(
+        &self,
+        board: &board::Board<WIDTH, HEIGHT>,
+    ) {
+        // tetrominos in spawn orientation only use the 3 first rows of their 4x4 grid, so each
+        // slot overlaps the empty last row of the previous one
+        let slot_height = 0.27;
+        let queue = board.queue();
+        for (slot, index) in queue.iter().enumerate() {
+            let grid_dimensions = GridDimensions {
+                x: 0.54,
+                y: 0.9 - slot_height * slot as f32 - 0.36,
+                width: 0.36,
+                height: 0.36,
+                cols: 4,
+                rows: 4,
+            };
+            self.draw_preview(
+                board::Board::<WIDTH, HEIGHT>::preview(*index),
+                &grid_dimensions,
+            );
+        }
+
+        if !queue.is_empty() {
+            let height = slot_height * queue.len() as f32;
+            self.draw_frame(&GridDimensions {
+                x: 0.54,
+                y: 0.9 - height,
+                width: 0.36,
+                height: height,
+                cols: 4,
+                rows: 4,
+            });
+        }
+    }
+
+    /// Draws the outline of a grid
+    fn draw_frame(&self, grid_dimensions: &GridDimensions) {
+        let frame_dimensions = GridDimensions {
+            cols: 1,
+            rows: 1,
+            ..*grid_dimensions
+        };
+        self.grid_cols_program.draw(&self.gl, &frame_dimensions);
+        self.grid_rows_program.draw(&self.gl, &frame_dimensions);
+    }
+
+    fn draw_preview(
+        &self,
+        preview: ([[bool; 4]; 4], board::Color),
+        grid_dimensions: &GridDimensions,
+    ) {
+        let (blocks, color) = preview;
+        for row in 0..blocks.len() {
+            for col in 0..blocks[row].len() {
+                if blocks[row][col] {
+                    self.blocks_program.draw(
+                        &self.gl,
+                        col,
+                        row,
+                        self.colors[&color],
+                        grid_dimensions,
+                    );
+                }
+            }
+        }
+    }
 }
 
 struct GridColsProgram {
@@ -372,6 +512,34 @@ impl BlocksProgram {
         );
     }
 
+    /// Draws only the border of a block
+    fn draw_outline(
+        &self,
+        gl: &WebGl2RenderingContext,
+        x: usize,
+        y: usize,
+        color: [f32; 4],
+        grid_dimensions: &GridDimensions,
+    ) {
+        gl.use_program(Some(&self.program));
+
+        // the corners of the block, in order around it
+        let block = self.create_block(x, y, grid_dimensions);
+        let vertices = vec![
+            block[0], block[1], block[2], block[3], block[6], block[7], block[4], block[5],
+        ];
+        let mut colors: Vec<f32> = vec![];
+        for _ in 0..(vertices.len() / 2) {
+            colors.extend_from_slice(&color);
+        }
+        self.buffer_data(gl, &vertices, &colors);
+        gl.draw_arrays(
+            WebGl2RenderingContext::LINE_LOOP,
+            0,
+            (vertices.len() / 2) as i32,
+        );
+    }
+
     fn buffer_data(&self, gl: &WebGl2RenderingContext, vertices: &Vec<f32>, colors: &Vec<f32>) {
         let buffer = gl.create_buffer().ok_or("cannot create buffer").unwrap();
         gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
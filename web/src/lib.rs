@@ -0,0 +1,744 @@
+#![feature(generic_const_exprs)]
+#![feature(hash_map_macro)]
+
+use bad_tetris_core::board;
+use bad_tetris_core::game;
+use bad_tetris_core::gravity;
+use bad_tetris_core::input;
+use bad_tetris_core::input::Action;
+use bad_tetris_core::randomizer;
+use bad_tetris_core::replay;
+use bad_tetris_core::save;
+use bad_tetris_core::streaks;
+use chrono::Local;
+use console_error_panic_hook;
+use debug_cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use web_sys::WebGl2RenderingContext;
+
+mod drawing;
+mod webgl;
+
+enum GameState {
+    Playing,
+    Paused,
+    GameOver,
+}
+
+struct App {
+    display: drawing::Display,
+    game: game::Game,
+    state: GameState,
+    last_update_time: i64,
+    // time not simulated yet, in 1/FRAMES_PER_SECOND ms so that a frame lasts exactly 1000
+    pending_time: i64,
+    // seed, settings and inputs of the current game
+    recording: replay::Replay,
+    // set when the game is a replay rather than played with the keyboard
+    playback: Option<Playback>,
+    // set when the game was resumed from a save, its recording misses the inputs played before
+    resumed: bool,
+    // frames left before the callout of the last clear is hidden
+    callout_frames: u32,
+    // keys pressed and released since the last simulated frame, oldest first
+    inputs: VecDeque<Input>,
+    // logs the events of every frame to the console, set by the `debug` query parameter
+    debug: bool,
+    // the key callbacks are members of app so that they live during its lifetime
+    keydown_callback: Closure<dyn Fn(&web_sys::Event)>,
+    keyup_callback: Closure<dyn Fn(&web_sys::Event)>,
+    // pauses the game when the page loses the focus or is hidden
+    blur_callback: Closure<dyn Fn(&web_sys::Event)>,
+    // saves the game when the page is unloaded
+    pagehide_callback: Closure<dyn Fn(&web_sys::Event)>,
+}
+
+/// Keys bound to each action by default, as named by `KeyboardEvent.key`
+const DEFAULT_KEY_BINDINGS: [(&str, Action); 14] = [
+    ("ArrowLeft", Action::Left),
+    ("ArrowRight", Action::Right),
+    ("ArrowDown", Action::SoftDrop),
+    (" ", Action::HardDrop),
+    ("ArrowUp", Action::RotateClockwise),
+    ("x", Action::RotateClockwise),
+    ("z", Action::RotateCounterclockwise),
+    ("Control", Action::RotateCounterclockwise),
+    ("a", Action::Rotate180),
+    ("c", Action::Hold),
+    ("Shift", Action::Hold),
+    ("Escape", Action::Pause),
+    ("p", Action::Pause),
+    ("Enter", Action::Restart),
+];
+
+/// Replay fed to the game instead of the keyboard
+struct Playback {
+    replay: replay::Replay,
+    // index of the next input of the replay to apply
+    next: usize,
+    paused: bool,
+}
+
+/// A key pressed or released by the player, kept until the frame during which it happened is
+/// simulated
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Input {
+    time_ms: i64,
+    action: Action,
+    pressed: bool,
+}
+
+fn action(key_bindings: &[(String, Action)], key: &str) -> Option<Action> {
+    key_bindings
+        .iter()
+        .find(|(bound_key, _)| bound_key == key)
+        .map(|(_, action)| *action)
+}
+
+#[wasm_bindgen(start)]
+fn start() -> Result<(), JsValue> {
+    // log panics to the console
+    console_error_panic_hook::set_once();
+
+    // get the canas with id 'canvas'
+    let canvas = web_sys::window()
+        .ok_or("cannot get window")?
+        .document()
+        .ok_or("cannot get document")?
+        .get_element_by_id("canvas")
+        .ok_or("cannot get canvas")?
+        .dyn_into::<web_sys::HtmlCanvasElement>()?;
+
+    // get a webgl2 context from the canvas
+    let gl = canvas
+        .get_context("webgl2")?
+        .ok_or("cannot get webgl2 context")?
+        .dyn_into::<WebGl2RenderingContext>()?;
+
+    let state = Rc::new(RefCell::new(None::<App>));
+    let state_copy = state.clone();
+    let keyup_state_copy = state.clone();
+    let blur_state_copy = state.clone();
+    let pagehide_state_copy = state.clone();
+    let key_bindings = Rc::new(key_bindings());
+    let keyup_key_bindings = key_bindings.clone();
+    let playback = query_parameter("replay").and_then(|replay| {
+        replay::Replay::parse(&replay)
+            .map_err(|error| {
+                web_sys::console::log_1(&format!("invalid replay: {}", error).into());
+            })
+            .ok()
+    });
+    // replays are watched without touching the game saved by the player
+    let save = match playback {
+        Some(_) => None,
+        None => load_save(),
+    };
+    let recording = match (&playback, &save) {
+        (Some(replay), _) => replay::Replay::new(replay.seed, replay.settings),
+        (None, Some(save)) => replay::Replay::new(save.seed, save.settings),
+        (None, None) => replay::Replay::new(new_seed(), settings()),
+    };
+    let resumed = save.is_some();
+    let game = match save {
+        Some(save) => game::Game::resume(save),
+        None => game::Game::new(recording.seed, recording.settings),
+    };
+    show_seed(recording.seed);
+    if resumed {
+        set_callout("Paused");
+    }
+    *state.borrow_mut() = Some(App {
+        display: drawing::Display::new(gl),
+        game: game,
+        // a resumed game waits for the player to unpause it
+        state: match resumed {
+            true => GameState::Paused,
+            false => GameState::Playing,
+        },
+        last_update_time: Local::now().timestamp_millis(),
+        pending_time: 0,
+        recording: recording,
+        playback: playback.map(|replay| Playback {
+            replay: replay,
+            next: 0,
+            paused: false,
+        }),
+        resumed: resumed,
+        callout_frames: 0,
+        inputs: VecDeque::new(),
+        debug: query_parameter("debug").is_some(),
+        keydown_callback: Closure::wrap(Box::new(move |event: &web_sys::Event| {
+            match event.clone().dyn_into::<web_sys::KeyboardEvent>() {
+                Ok(keyboard_event) => match action(&key_bindings, &keyboard_event.key()) {
+                    Some(action) => {
+                        // bound keys would scroll the page otherwise (space, arrows)
+                        keyboard_event.prevent_default();
+                        // held keys are repeated by the game, not by the keyboard
+                        if !keyboard_event.repeat() {
+                            push_input(state_copy.borrow_mut().as_mut().unwrap(), action, true);
+                        }
+                    }
+                    None => web_sys::console::log_1(&keyboard_event.key().into()),
+                },
+                Err(_) => (),
+            }
+        })),
+        keyup_callback: Closure::wrap(Box::new(move |event: &web_sys::Event| {
+            match event.clone().dyn_into::<web_sys::KeyboardEvent>() {
+                Ok(keyboard_event) => match action(&keyup_key_bindings, &keyboard_event.key()) {
+                    Some(action) => push_input(
+                        keyup_state_copy.borrow_mut().as_mut().unwrap(),
+                        action,
+                        false,
+                    ),
+                    None => (),
+                },
+                Err(_) => (),
+            }
+        })),
+        blur_callback: Closure::wrap(Box::new(move |event: &web_sys::Event| {
+            let hidden = web_sys::window()
+                .and_then(|window| window.document())
+                .is_some_and(|document| document.hidden());
+            if event.type_() == "blur" || hidden {
+                pause(blur_state_copy.borrow_mut().as_mut().unwrap());
+            }
+        })),
+        pagehide_callback: Closure::wrap(Box::new(move |_event: &web_sys::Event| {
+            save_game(pagehide_state_copy.borrow().as_ref().unwrap());
+        })),
+    });
+
+    web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback(
+            "keydown",
+            state
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .keydown_callback
+                .as_ref()
+                .as_ref()
+                .unchecked_ref(),
+        )
+        .expect("add_event_listener failed");
+
+    web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback(
+            "keyup",
+            state
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .keyup_callback
+                .as_ref()
+                .as_ref()
+                .unchecked_ref(),
+        )
+        .expect("add_event_listener failed");
+
+    web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback(
+            "blur",
+            state
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .blur_callback
+                .as_ref()
+                .as_ref()
+                .unchecked_ref(),
+        )
+        .expect("add_event_listener failed");
+
+    web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .add_event_listener_with_callback(
+            "visibilitychange",
+            state
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .blur_callback
+                .as_ref()
+                .as_ref()
+                .unchecked_ref(),
+        )
+        .expect("add_event_listener failed");
+
+    web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback(
+            "pagehide",
+            state
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .pagehide_callback
+                .as_ref()
+                .as_ref()
+                .unchecked_ref(),
+        )
+        .expect("add_event_listener failed");
+
+    let f = Rc::new(RefCell::new(None));
+    let g = f.clone();
+
+    *g.borrow_mut() = Some(Closure::new(move || {
+        update(
+            Local::now().timestamp_millis(),
+            &mut state.borrow_mut().as_mut().unwrap(),
+        );
+        render(&state.borrow().as_ref().unwrap());
+        request_animation_frame(f.borrow().as_ref().unwrap());
+    }));
+
+    request_animation_frame(g.borrow().as_ref().unwrap());
+
+    Ok(())
+}
+
+/// Queues a key event, the board is only changed by `update`
+fn push_input(app: &mut App, action: Action, pressed: bool) {
+    app.inputs.push_back(Input {
+        time_ms: Local::now().timestamp_millis(),
+        action: action,
+        pressed: pressed,
+    });
+}
+
+/// Applies the queued inputs that happened up to a given time, oldest first
+fn handle_inputs(app: &mut App, until_ms: i64) {
+    while let Some(input) = app.inputs.front() {
+        if input.time_ms > until_ms {
+            break;
+        }
+        let input = app.inputs.pop_front().unwrap();
+        if app.playback.is_some() {
+            playback_control(app, input.action, input.pressed);
+        } else {
+            if input.pressed {
+                if let GameState::Playing = app.state {
+                    app.recording.record(app.game.tick(), input.action, true);
+                }
+                key_down(app, input.action);
+            } else {
+                app.recording.record(app.game.tick(), input.action, false);
+                app.game.release(input.action);
+            }
+        }
+    }
+}
+
+/// Number of frames skipped by seeking forward or backward in a replay
+const SEEK_FRAMES: u64 = 5 * gravity::FRAMES_PER_SECOND as u64;
+
+/// Controls a replay with the keyboard: pause toggles playing, left and right seek backward and
+/// forward, restart goes back to the beginning
+fn playback_control(app: &mut App, action: Action, pressed: bool) {
+    if !pressed {
+        return;
+    }
+    let playback = app.playback.as_mut().unwrap();
+    match action {
+        Action::Pause => {
+            playback.paused = !playback.paused;
+            app.pending_time = 0;
+        }
+        Action::Left => seek(app, app.game.tick().saturating_sub(SEEK_FRAMES)),
+        Action::Right => seek(app, app.game.tick() + SEEK_FRAMES),
+        Action::Restart => seek(app, 0),
+        _ => (),
+    }
+}
+
+/// Plays the replay again from the beginning up to a given frame
+fn seek(app: &mut App, tick: u64) {
+    let replay = app.playback.as_ref().unwrap().replay.clone();
+    new_game(app, replay.seed, replay.settings);
+    app.playback.as_mut().unwrap().next = 0;
+    while app.game.tick() < tick && matches!(app.state, GameState::Playing) {
+        simulate(app);
+    }
+}
+
+fn key_down(app: &mut App, action: Action) {
+    match app.state {
+        GameState::Playing => match action {
+            Action::Pause => pause(app),
+            action => app.game.press(action),
+        },
+        GameState::Paused => match action {
+            Action::Pause => resume(app),
+            _ => (),
+        },
+        GameState::GameOver => match action {
+            Action::Restart => restart(app),
+            _ => (),
+        },
+    }
+}
+
+/// Freezes the game, hides the board and saves the game so that it can be resumed later
+fn pause(app: &mut App) {
+    if let Some(playback) = &mut app.playback {
+        playback.paused = true;
+        return;
+    }
+    if let GameState::Playing = app.state {
+        app.state = GameState::Paused;
+        // keys released while paused are not seen by the game, they are released now (and
+        // recorded so) instead
+        for action in [Action::Left, Action::Right, Action::SoftDrop] {
+            app.recording.record(app.game.tick(), action, false);
+            app.game.release(action);
+        }
+        set_callout("Paused");
+        save_game(app);
+    }
+}
+
+fn resume(app: &mut App) {
+    if let GameState::Paused = app.state {
+        app.state = GameState::Playing;
+        // the time spent paused is not simulated
+        app.pending_time = 0;
+        app.callout_frames = 0;
+        set_callout("");
+    }
+}
+
+/// Returns the value of a query parameter of the page's URL
+fn query_parameter(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    web_sys::UrlSearchParams::new_with_str(&search)
+        .ok()?
+        .get(name)
+}
+
+fn request_animation_frame(f: &Closure<dyn FnMut()>) {
+    web_sys::window()
+        .unwrap()
+        .request_animation_frame(f.as_ref().unchecked_ref())
+        .expect("request_animation_frame failed");
+}
+
+/// Returns the seed given by the `seed` query parameter of the page, so that players can share
+/// games with the same tetrominos, or a random one
+fn new_seed() -> u64 {
+    let seed = match query_parameter("seed") {
+        Some(seed) => seed.parse().unwrap_or_else(|_| {
+            web_sys::console::log_1(&format!("invalid seed: {}", seed).into());
+            rand::random()
+        }),
+        None => rand::random(),
+    };
+    seed
+}
+
+/// Shows the seed of the game on the page
+fn show_seed(seed: u64) {
+    set_text("seed", &format!("seed: {}", seed));
+}
+
+/// Key of the saved game in the local storage of the page
+const SAVE_KEY: &str = "save";
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Saves the game in progress to the local storage, replays are not saved
+fn save_game(app: &App) {
+    if app.playback.is_some() || matches!(app.state, GameState::GameOver) {
+        return;
+    }
+    if let Some(storage) = local_storage() {
+        if storage
+            .set_item(SAVE_KEY, &app.game.save().serialize())
+            .is_err()
+        {
+            web_sys::console::log_1(&"cannot save the game".into());
+        }
+    }
+}
+
+/// Returns the saved game if there is one and the player wants to resume it. It is removed
+/// otherwise, as well as saves written by another version of the game
+fn load_save() -> Option<save::Save<10, 20>> {
+    let storage = local_storage()?;
+    let text = storage.get_item(SAVE_KEY).ok()??;
+    let resume = match save::Save::parse(&text) {
+        Ok(save) => web_sys::window()?
+            .confirm_with_message("Resume the saved game?")
+            .unwrap_or(false)
+            .then_some(save),
+        Err(error) => {
+            web_sys::console::log_1(&format!("invalid save: {}", error).into());
+            None
+        }
+    };
+    if resume.is_none() {
+        remove_save();
+    }
+    resume
+}
+
+fn remove_save() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(SAVE_KEY);
+    }
+}
+
+/// Reads the settings from the `randomizer`, `das`, `arr` and `sdf` (in frames) query parameters
+/// of the page
+fn settings() -> replay::Settings {
+    let parameter = |name: &str, default: u32| {
+        query_parameter(name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    };
+    let randomizer = match query_parameter("randomizer") {
+        Some(name) => randomizer::Kind::from_name(&name).unwrap_or_else(|| {
+            web_sys::console::log_1(&format!("unknown randomizer: {}", name).into());
+            game::DEFAULT_SETTINGS.randomizer
+        }),
+        None => game::DEFAULT_SETTINGS.randomizer,
+    };
+    replay::Settings {
+        randomizer: randomizer,
+        das: parameter("das", game::DEFAULT_SETTINGS.das),
+        arr: parameter("arr", game::DEFAULT_SETTINGS.arr),
+        soft_drop_factor: parameter("sdf", game::DEFAULT_SETTINGS.soft_drop_factor)
+            .clamp(1, gravity::MAX_SOFT_DROP_FACTOR),
+    }
+}
+
+/// Reads the key bindings, the `keys` query parameter (e.g. `keys=rotate-cw:w,hold:q`) replaces
+/// the default keys of the actions it binds
+fn key_bindings() -> Vec<(String, Action)> {
+    let mut key_bindings: Vec<(String, Action)> = DEFAULT_KEY_BINDINGS
+        .iter()
+        .map(|(key, action)| (key.to_string(), *action))
+        .collect();
+    if let Some(text) = query_parameter("keys") {
+        match input::parse_bindings(&text) {
+            Ok(bindings) => {
+                key_bindings
+                    .retain(|(_, action)| !bindings.iter().any(|(_, bound)| bound == action));
+                key_bindings.extend(bindings);
+            }
+            Err(error) => {
+                web_sys::console::log_1(&format!("invalid key bindings: {}", error).into());
+            }
+        }
+    }
+    key_bindings
+}
+
+/// Starts a new game with an empty board
+fn restart(app: &mut App) {
+    web_sys::console::log_1(&"restart".into());
+    new_game(app, new_seed(), app.recording.settings);
+}
+
+fn new_game(app: &mut App, seed: u64, settings: replay::Settings) {
+    app.game = game::Game::new(seed, settings);
+    show_seed(seed);
+    app.recording = replay::Replay::new(seed, settings);
+    app.callout_frames = 0;
+    set_callout("");
+    app.state = GameState::Playing;
+    app.resumed = false;
+    set_replay_link(None);
+}
+
+/// Longest time simulated by a single update, so that the game does not run hundreds of frames at
+/// once after the page was throttled
+const MAX_UPDATE_DURATION_MS: i64 = 250;
+
+fn update(time_ms: i64, app: &mut App) {
+    let elapsed = (time_ms - app.last_update_time).clamp(0, MAX_UPDATE_DURATION_MS);
+    app.last_update_time = time_ms;
+    let playback_paused = app
+        .playback
+        .as_ref()
+        .is_some_and(|playback| playback.paused);
+    if matches!(app.state, GameState::Playing) && !playback_paused {
+        app.pending_time += elapsed * gravity::FRAMES_PER_SECOND;
+        while app.pending_time >= 1000 {
+            app.pending_time -= 1000;
+            // time at which the frame ends
+            let frame_time_ms = time_ms - app.pending_time / gravity::FRAMES_PER_SECOND;
+            step(app, frame_time_ms);
+            if !matches!(app.state, GameState::Playing) {
+                break;
+            }
+        }
+    }
+    // nothing is simulated while the game or the replay is paused or the game is over, inputs
+    // (e.g. resuming) are applied as they come
+    if !matches!(app.state, GameState::Playing) || playback_paused {
+        handle_inputs(app, time_ms);
+    }
+}
+
+/// Simulates one frame, starting with the inputs that happened until its end
+fn step(app: &mut App, frame_time_ms: i64) {
+    handle_inputs(app, frame_time_ms);
+    simulate(app);
+}
+
+/// Simulates one frame of the game, starting with the inputs of the replay if one is played
+fn simulate(app: &mut App) {
+    if let Some(playback) = &mut app.playback {
+        let mut inputs = vec![];
+        while let Some(input) = playback.replay.inputs.get(playback.next) {
+            if input.tick > app.game.tick() {
+                break;
+            }
+            inputs.push(*input);
+            playback.next += 1;
+        }
+        for input in inputs {
+            if input.pressed {
+                key_down(app, input.action);
+            } else {
+                app.game.release(input.action);
+            }
+        }
+    }
+    if let GameState::Playing = app.state {
+        let frame = app.game.step();
+
+        if app.callout_frames > 0 {
+            app.callout_frames -= 1;
+            if app.callout_frames == 0 {
+                set_callout("");
+            }
+        }
+
+        for clear in &frame.clears {
+            set_callout(&callout(clear));
+            app.callout_frames = CALLOUT_FRAMES;
+        }
+        if app.debug {
+            for event in &frame.events {
+                web_sys::console::log_1(&format!("{:?}", event).into());
+                if let board::Event::LineClear(_) = event {
+                    let scoring = app.game.scoring();
+                    web_sys::console::log_1(
+                        &format!(
+                            "score: {}, level: {}, lines: {}",
+                            scoring.score(),
+                            scoring.level(),
+                            scoring.lines()
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
+        if let Some(top_out) = app.game.top_out() {
+            web_sys::console::log_1(
+                &format!("game over: {:?}\n{}", top_out, app.game.board().to_text()).into(),
+            );
+            app.state = GameState::GameOver;
+            if app.playback.is_none() {
+                remove_save();
+            }
+            // the inputs played before a resumed game was saved are lost
+            if app.playback.is_none() && !app.resumed {
+                let replay = app.recording.serialize();
+                web_sys::console::log_1(&format!("replay: {}", replay).into());
+                set_replay_link(Some(&replay));
+            }
+        }
+    }
+}
+
+/// Frames during which the callout of a clear stays on screen
+const CALLOUT_FRAMES: u32 = 90;
+
+/// Names of the tetrominos, indexed as in `Board::TETROMINOS`
+const TETROMINO_NAMES: [&str; 7] = ["I", "O", "T", "L", "J", "S", "Z"];
+
+/// Describes a clear the way the guideline announces it, e.g. "Back-to-Back T-Spin Double"
+fn callout(clear: &streaks::Clear) -> String {
+    let mut words = vec![];
+    if clear.back_to_back {
+        words.push("Back-to-Back".to_string());
+    }
+    if let (Some(lock), Some(spin)) = (clear.lock, clear.spin()) {
+        if let board::Spin::Mini = spin {
+            words.push("Mini".to_string());
+        }
+        words.push(format!("{}-Spin", TETROMINO_NAMES[lock.index]));
+    }
+    match clear.rows {
+        0 => (),
+        1 => words.push("Single".to_string()),
+        2 => words.push("Double".to_string()),
+        3 => words.push("Triple".to_string()),
+        4 => words.push("Tetris".to_string()),
+        rows => words.push(format!("{} Lines", rows)),
+    }
+    if clear.lock.is_none() {
+        words.push("Chain".to_string());
+    }
+    if clear.combo > 0 {
+        words.push(format!("{} Combo", clear.combo));
+    }
+    if clear.perfect_clear {
+        words.push("Perfect Clear".to_string());
+    }
+    words.join(" ")
+}
+
+/// Shows a text over the game in the element with id 'callout'
+fn set_callout(text: &str) {
+    set_text("callout", text);
+}
+
+/// Shows a link playing a replay in the element with id 'replay', or hides it
+fn set_replay_link(replay: Option<&str>) {
+    if let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id("replay"))
+    {
+        element.set_inner_html(&match replay {
+            Some(replay) => format!("<a href=\"?replay={}\">watch the replay</a>", replay),
+            None => String::new(),
+        });
+    }
+}
+
+/// Replaces the text of the element of the page with a given id
+fn set_text(id: &str, text: &str) {
+    if let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+    {
+        element.set_text_content(Some(text));
+    }
+}
+
+fn render(app: &App) {
+    app.display.clear();
+    if let GameState::Paused = app.state {
+        // the board is hidden so that pausing does not give time to think
+        app.display.draw_board_grid::<10, 20>();
+        return;
+    }
+    app.display.draw_board(app.game.board());
+    app.display.draw_held(app.game.board());
+    app.display.draw_queue(app.game.board());
+}